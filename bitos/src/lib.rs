@@ -1,3 +1,5 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub mod prelude {
     pub use bitos_core::*;
     pub use bitos_macro::*;
@@ -5,3 +7,7 @@ pub mod prelude {
 }
 
 pub use prelude::*;
+
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+pub use serde as __serde;
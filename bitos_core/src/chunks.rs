@@ -0,0 +1,35 @@
+use crate::integer::mask_to_width;
+
+/// Returns the `index`-th chunk of `chunk_len` bits in `value`, where chunks are laid out
+/// contiguously starting at bit `offset`.
+#[inline(always)]
+pub const fn chunk(value: u64, offset: u8, chunk_len: u8, index: usize) -> u64 {
+    let start = offset as u32 + chunk_len as u32 * index as u32;
+    mask_to_width(value >> start, chunk_len)
+}
+
+/// Returns `value` with the `index`-th chunk of `chunk_len` bits replaced by the lowest
+/// `chunk_len` bits of `chunk`, where chunks are laid out contiguously starting at bit `offset`.
+#[inline(always)]
+pub const fn with_chunk(value: u64, offset: u8, chunk_len: u8, index: usize, chunk: u64) -> u64 {
+    let start = offset as u32 + chunk_len as u32 * index as u32;
+    let mask = mask_to_width(u64::MAX, chunk_len) << start;
+
+    (value & !mask) | ((chunk << start) & mask)
+}
+
+/// Splits `value` into `N` chunks of `chunk_len` bits, laid out contiguously starting at bit
+/// `offset`.
+#[inline(always)]
+pub fn split_into<const N: usize>(value: u64, offset: u8, chunk_len: u8) -> [u64; N] {
+    core::array::from_fn(|i| chunk(value, offset, chunk_len, i))
+}
+
+/// Returns `value` with `N` chunks of `chunk_len` bits, laid out contiguously starting at bit
+/// `offset`, replaced by `chunks`. Inverse of [`split_into`].
+#[inline(always)]
+pub fn join_chunks<const N: usize>(value: u64, offset: u8, chunk_len: u8, chunks: [u64; N]) -> u64 {
+    chunks.into_iter().enumerate().fold(value, |acc, (i, c)| {
+        with_chunk(acc, offset, chunk_len, i, c)
+    })
+}
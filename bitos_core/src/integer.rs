@@ -1,6 +1,6 @@
-use std::ops::{
-    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-    ShrAssign,
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl,
+    ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
 use bitut::BitUtils;
@@ -99,9 +99,26 @@ impl UnsignedInt for u64 {
     }
 }
 
+/// Masks `value` so that only its lowest `width` bits are kept. Widths of 64 or more keep the
+/// value as is.
+#[inline(always)]
+pub const fn mask_to_width(value: u64, width: u8) -> u64 {
+    if width >= 64 {
+        value
+    } else {
+        value & ((1 << width) - 1)
+    }
+}
+
+/// Returns a mask where only bits `start..end` are set. Ends of 64 or more are clamped to 64.
+#[inline(always)]
+pub const fn range_mask(start: u8, end: u8) -> u64 {
+    mask_to_width(u64::MAX, end) & !mask_to_width(u64::MAX, start)
+}
+
 #[inline(always)]
 const fn unsigned_mask(bits: usize) -> u64 {
-    (1 << bits) - 1
+    range_mask(0, bits as u8)
 }
 
 #[diagnostic::on_unimplemented(
@@ -119,14 +136,14 @@ pub trait IsStorageForBits<const LEN: usize> {}
 #[repr(transparent)]
 pub struct UInt<T, const LEN: usize>(T);
 
-impl<T: std::fmt::Debug, const LEN: usize> std::fmt::Debug for UInt<T, LEN> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::Debug, const LEN: usize> core::fmt::Debug for UInt<T, LEN> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl<T: std::fmt::UpperHex, const LEN: usize> std::fmt::UpperHex for UInt<T, LEN> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::UpperHex, const LEN: usize> core::fmt::UpperHex for UInt<T, LEN> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -137,19 +154,181 @@ where
 {
     #[inline(always)]
     pub fn new(value: T) -> Self {
-        Self(value & T::new(const { unsigned_mask(LEN) }))
+        Self(<T as UnsignedInt>::new(mask_to_width(
+            <T as UnsignedInt>::value(value),
+            LEN as u8,
+        )))
     }
 
     #[inline(always)]
     pub fn value(self) -> T {
         let value = self.0;
 
-        unsafe { std::hint::assert_unchecked(value <= T::new(const { unsigned_mask(LEN) })) };
+        debug_assert!(
+            value <= T::new(const { unsigned_mask(LEN) }),
+            "invariant violated: value {} does not fit within {LEN} bits",
+            <T as UnsignedInt>::value(value),
+        );
+
+        unsafe { core::hint::assert_unchecked(value <= T::new(const { unsigned_mask(LEN) })) };
         value
     }
+
+    /// Tries to create a new value of this integer type from an [`i64`]. Returns [`None`] if the
+    /// value is negative or does not fit within `LEN` bits.
+    #[inline(always)]
+    pub fn try_from_signed(value: i64) -> Option<Self> {
+        let value = u64::try_from(value).ok()?;
+        Self::try_from(value).ok()
+    }
+
+    /// Returns the binary-reflected Gray code of this value.
+    #[inline(always)]
+    pub fn to_gray(self) -> Self {
+        let value = <T as UnsignedInt>::value(self.value());
+        Self::new(T::new(value ^ (value >> 1)))
+    }
+
+    /// Decodes this value from it's binary-reflected Gray code. This is the inverse of
+    /// [`UInt::to_gray`].
+    #[inline(always)]
+    pub fn from_gray(self) -> Self {
+        let mut value = <T as UnsignedInt>::value(self.value());
+        let mut shift = 1;
+        while shift < LEN {
+            value ^= value >> shift;
+            shift <<= 1;
+        }
+
+        Self::new(T::new(value))
+    }
+
+    /// Reverses the order of the `LEN` bits of this value.
+    #[inline(always)]
+    pub fn reverse_bits(self) -> Self {
+        let value = <T as UnsignedInt>::value(self.value());
+        Self::new(T::new(value.reverse_bits() >> (64 - LEN)))
+    }
+
+    /// Adds one to this value, wrapping around to zero at `2^LEN`.
+    #[inline(always)]
+    pub fn wrapping_increment(self) -> Self {
+        let value = <T as UnsignedInt>::value(self.value());
+        Self::new(T::new(value.wrapping_add(1)))
+    }
+
+    /// Subtracts one from this value, wrapping around to `2^LEN - 1` at zero.
+    #[inline(always)]
+    pub fn wrapping_decrement(self) -> Self {
+        let value = <T as UnsignedInt>::value(self.value());
+        Self::new(T::new(value.wrapping_sub(1)))
+    }
+
+    /// Raises this value to the power of `exp`, wrapping around at `2^LEN`.
+    #[inline(always)]
+    pub fn pow(self, exp: u32) -> Self {
+        let value = <T as UnsignedInt>::value(self.value());
+        Self::new(T::new(value.wrapping_pow(exp)))
+    }
+
+    /// Raises this value to the power of `exp`, returning [`None`] if the result does not fit
+    /// within `LEN` bits.
+    #[inline(always)]
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        let value = <T as UnsignedInt>::value(self.value());
+        <Self as TryFrom<u64>>::try_from(value.checked_pow(exp)?).ok()
+    }
+
+    /// Returns whether this value is a power of two, i.e. has exactly one bit set.
+    #[inline(always)]
+    pub fn is_power_of_two(self) -> bool {
+        <T as UnsignedInt>::value(self.value()).is_power_of_two()
+    }
+
+    /// Returns the smallest power of two greater than or equal to this value. If that power does
+    /// not fit within `LEN` bits, it wraps around to zero, e.g. `u4::new(9).next_power_of_two()`
+    /// is `u4::new(0)`.
+    #[inline(always)]
+    pub fn next_power_of_two(self) -> Self {
+        self.checked_next_power_of_two()
+            .unwrap_or(Self::new(T::new(0)))
+    }
+
+    /// Returns the smallest power of two greater than or equal to this value, or [`None`] if it
+    /// does not fit within `LEN` bits.
+    #[inline(always)]
+    pub fn checked_next_power_of_two(self) -> Option<Self> {
+        let value = <T as UnsignedInt>::value(self.value());
+        <Self as TryFrom<u64>>::try_from(value.checked_next_power_of_two()?).ok()
+    }
 }
 
-pub struct ValueDoesNotFitErr;
+macro_rules! impl_uint_const {
+    ($($prim:ty),*) => {
+        $(
+            impl<const LEN: usize> UInt<$prim, LEN>
+            where
+                $prim: IsStorageForBits<LEN>,
+            {
+                /// Creates a new value of this integer type in a const context. The value is
+                /// masked to fit within `LEN` bits.
+                #[inline(always)]
+                pub const fn const_new(value: $prim) -> Self {
+                    Self(value & (unsigned_mask(LEN) as $prim))
+                }
+
+                /// Returns the value of this integer type in a const context.
+                #[inline(always)]
+                pub const fn const_value(self) -> $prim {
+                    self.0
+                }
+
+                /// Returns a value where only bits `start..end` are set. `end` is clamped to
+                /// `LEN`.
+                #[inline(always)]
+                pub const fn mask(start: u8, end: u8) -> Self {
+                    let end = if end as usize > LEN { LEN as u8 } else { end };
+                    Self(range_mask(start, end) as $prim)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_const!(u8, u16, u32, u64);
+
+/// Error returned when a value does not fit within the bit width of the target integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueDoesNotFitErr {
+    width: u8,
+}
+
+impl ValueDoesNotFitErr {
+    /// Creates a new error for a target integer of `width` bits.
+    #[inline(always)]
+    pub const fn new(width: u8) -> Self {
+        Self { width }
+    }
+
+    /// Returns the bit width of the target integer.
+    #[inline(always)]
+    pub const fn width(&self) -> u8 {
+        self.width
+    }
+}
+
+impl core::fmt::Display for ValueDoesNotFitErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "value does not fit in the target integer width {}",
+            self.width
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValueDoesNotFitErr {}
 
 impl<T, const LEN: usize> TryFrom<u64> for UInt<T, LEN>
 where
@@ -161,7 +340,7 @@ where
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         (value <= const { unsigned_mask(LEN) })
             .then(|| Self::new(T::new(value)))
-            .ok_or(ValueDoesNotFitErr)
+            .ok_or(ValueDoesNotFitErr::new(LEN as u8))
     }
 }
 
@@ -175,6 +354,56 @@ where
     }
 }
 
+impl<T, const LEN: usize> PartialEq<u64> for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &u64) -> bool {
+        <T as UnsignedInt>::value(self.value()) == *other
+    }
+}
+
+impl<T, const LEN: usize> PartialOrd<u64> for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &u64) -> Option<core::cmp::Ordering> {
+        <T as UnsignedInt>::value(self.value()).partial_cmp(other)
+    }
+}
+
+// comparisons against u64 are covered by the impls above, and a separate impl for u64 storage
+// would conflict with them
+macro_rules! impl_uint_cmp_storage {
+    ($($storage:ty),*) => {
+        $(
+            impl<const LEN: usize> PartialEq<$storage> for UInt<$storage, LEN>
+            where
+                $storage: IsStorageForBits<LEN>,
+            {
+                #[inline(always)]
+                fn eq(&self, other: &$storage) -> bool {
+                    self.value() == *other
+                }
+            }
+
+            impl<const LEN: usize> PartialOrd<$storage> for UInt<$storage, LEN>
+            where
+                $storage: IsStorageForBits<LEN>,
+            {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$storage) -> Option<core::cmp::Ordering> {
+                    self.value().partial_cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_cmp_storage!(u8, u16, u32);
+
 impl<T, const LEN: usize> UnsignedInt for UInt<T, LEN>
 where
     T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
@@ -196,9 +425,11 @@ where
         self.0.bit(index)
     }
 
+    // the fallible methods check indices against `LEN` rather than the width of the storage,
+    // as bits past `LEN` aren't part of the value
     #[inline(always)]
     fn try_bit(self, index: u8) -> Option<bool> {
-        self.0.try_bit(index)
+        ((index as usize) < LEN).then(|| self.0.bit(index))
     }
 
     #[inline(always)]
@@ -208,6 +439,10 @@ where
 
     #[inline(always)]
     fn try_with_bit(self, index: u8, value: bool) -> Option<Self> {
+        if index as usize >= LEN {
+            return None;
+        }
+
         self.0.try_with_bit(index, value).map(Self::new)
     }
 
@@ -218,6 +453,10 @@ where
 
     #[inline(always)]
     fn try_bits(self, start: u8, end: u8) -> Option<Self> {
+        if end as usize > LEN {
+            return None;
+        }
+
         self.0.try_bits(start, end).map(Self::new)
     }
 
@@ -228,12 +467,65 @@ where
 
     #[inline(always)]
     fn try_with_bits(self, start: u8, end: u8, value: Self) -> Option<Self> {
+        if end as usize > LEN {
+            return None;
+        }
+
         self.0
             .try_with_bits(start, end, value.value())
             .map(Self::new)
     }
 }
 
+// arithmetic, wrapping around at `2^LEN`
+impl<T, const LEN: usize> Add for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        let value = <T as UnsignedInt>::value(self.value())
+            .wrapping_add(<T as UnsignedInt>::value(rhs.value()));
+        Self::new(T::new(value))
+    }
+}
+
+impl<T, const LEN: usize> AddAssign for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, const LEN: usize> Sub for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let value = <T as UnsignedInt>::value(self.value())
+            .wrapping_sub(<T as UnsignedInt>::value(rhs.value()));
+        Self::new(T::new(value))
+    }
+}
+
+impl<T, const LEN: usize> SubAssign for UInt<T, LEN>
+where
+    T: UnsignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
 // bit ops
 impl<T, const LEN: usize> BitOr for UInt<T, LEN>
 where
@@ -409,6 +701,175 @@ seq!(N in 33..64 {
 #[allow(non_camel_case_types)]
 pub type u64 = sealed::U64;
 
+/// Type-level bit width, used to name the unsigned integer type of a given width through
+/// [`WidthInt`].
+pub struct Width<const BITS: usize>;
+
+/// Trait mapping a [`Width`] to the unsigned integer type with exactly that many bits.
+pub trait WidthInt {
+    /// The unsigned integer type of this width.
+    type UInt: UnsignedInt;
+}
+
+seq!(N in 1..=64 {
+    impl WidthInt for Width<N> {
+        type UInt = u~N;
+    }
+});
+
+/// Trait for unsigned integer types of which `N` values can be packed side by side into a
+/// single unsigned integer type, [`PackedArray::Packed`].
+pub trait PackedArray<const N: usize>: UnsignedInt {
+    /// The unsigned integer type holding `N` values of this type.
+    type Packed: UnsignedInt;
+}
+
+macro_rules! impl_packed_array {
+    ($($elem:ident => $max:literal),*) => {
+        $(
+            seq!(N in 1..=$max {
+                impl PackedArray<N> for $elem {
+                    type Packed = <Width<{ <$elem as UnsignedInt>::BITS * N }> as WidthInt>::UInt;
+                }
+            });
+        )*
+    };
+}
+
+impl_packed_array!(
+    u1 => 64, u2 => 32, u3 => 21, u4 => 16, u5 => 12, u6 => 10, u7 => 9, u8 => 8, u9 => 7,
+    u10 => 6, u11 => 5, u12 => 5, u13 => 4, u14 => 4, u15 => 4, u16 => 4, u17 => 3, u18 => 3,
+    u19 => 3, u20 => 3, u21 => 3, u22 => 2, u23 => 2, u24 => 2, u25 => 2, u26 => 2, u27 => 2,
+    u28 => 2, u29 => 2, u30 => 2, u31 => 2, u32 => 2, u33 => 1, u34 => 1, u35 => 1, u36 => 1,
+    u37 => 1, u38 => 1, u39 => 1, u40 => 1, u41 => 1, u42 => 1, u43 => 1, u44 => 1, u45 => 1,
+    u46 => 1, u47 => 1, u48 => 1, u49 => 1, u50 => 1, u51 => 1, u52 => 1, u53 => 1, u54 => 1,
+    u55 => 1, u56 => 1, u57 => 1, u58 => 1, u59 => 1, u60 => 1, u61 => 1, u62 => 1, u63 => 1,
+    u64 => 1
+);
+
+/// Trait for unsigned integer types which can be concatenated with a value of type `Rhs`,
+/// placing `Rhs` above this type's bits in a single unsigned integer type,
+/// [`Concat::Output`].
+pub trait Concat<Rhs: UnsignedInt>: UnsignedInt {
+    /// The unsigned integer type holding both values.
+    type Output: UnsignedInt;
+}
+
+macro_rules! impl_concat {
+    ($($lhs:ident => $max:literal),*) => {
+        $(
+            seq!(N in 1..=$max {
+                impl Concat<u~N> for $lhs {
+                    type Output = <Width<{ <$lhs as UnsignedInt>::BITS + N }> as WidthInt>::UInt;
+                }
+            });
+        )*
+    };
+}
+
+impl_concat!(
+    u1 => 63, u2 => 62, u3 => 61, u4 => 60, u5 => 59, u6 => 58, u7 => 57, u8 => 56, u9 => 55,
+    u10 => 54, u11 => 53, u12 => 52, u13 => 51, u14 => 50, u15 => 49, u16 => 48, u17 => 47,
+    u18 => 46, u19 => 45, u20 => 44, u21 => 43, u22 => 42, u23 => 41, u24 => 40, u25 => 39,
+    u26 => 38, u27 => 37, u28 => 36, u29 => 35, u30 => 34, u31 => 33, u32 => 32, u33 => 31,
+    u34 => 30, u35 => 29, u36 => 28, u37 => 27, u38 => 26, u39 => 25, u40 => 24, u41 => 23,
+    u42 => 22, u43 => 21, u44 => 20, u45 => 19, u46 => 18, u47 => 17, u48 => 16, u49 => 15,
+    u50 => 14, u51 => 13, u52 => 12, u53 => 11, u54 => 10, u55 => 9, u56 => 8, u57 => 7, u58 => 6,
+    u59 => 5, u60 => 4, u61 => 3, u62 => 2, u63 => 1
+);
+
+macro_rules! impl_uint_from_prim {
+    ($($storage:ident in $start:literal..$end:literal: from [$($from:ident),*], try_from [$($try_from:ident),*];)*) => {
+        $(
+            seq!(N in $start..$end {
+                $(
+                    impl From<$from> for UInt<$storage, N> {
+                        #[inline(always)]
+                        fn from(value: $from) -> Self {
+                            Self::new(value as $storage)
+                        }
+                    }
+                )*
+
+                $(
+                    impl TryFrom<$try_from> for UInt<$storage, N> {
+                        type Error = ValueDoesNotFitErr;
+
+                        #[inline(always)]
+                        fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                            <Self as TryFrom<u64>>::try_from(value as u64)
+                        }
+                    }
+                )*
+            });
+        )*
+    };
+}
+
+impl_uint_from_prim! {
+    u8 in 1..8: from [], try_from [u8, u16, u32];
+    u16 in 9..16: from [u8], try_from [u16, u32];
+    u32 in 17..32: from [u8, u16], try_from [u32];
+    u64 in 33..64: from [u8, u16, u32], try_from [];
+}
+
+macro_rules! impl_prim_from_uint {
+    ($($storage:ident in $start:literal..$end:literal: into [$($into:ident),*];)*) => {
+        $(
+            seq!(N in $start..$end {
+                $(
+                    impl From<UInt<$storage, N>> for $into {
+                        #[inline(always)]
+                        fn from(value: UInt<$storage, N>) -> Self {
+                            value.value() as $into
+                        }
+                    }
+                )*
+            });
+        )*
+    };
+}
+
+// conversions into u64 are covered by the generic impl
+impl_prim_from_uint! {
+    u8 in 1..8: into [u8, u16, u32];
+    u16 in 9..16: into [u16, u32];
+    u32 in 17..32: into [u32];
+}
+
+impl From<bool> for u1 {
+    #[inline(always)]
+    fn from(value: bool) -> Self {
+        Self::new(value as u8)
+    }
+}
+
+#[cfg(feature = "ux")]
+macro_rules! impl_ux_conversions {
+    ($($storage:ident in $start:literal..$end:literal),*) => {
+        $(
+            seq!(N in $start..$end {
+                impl From<ux::u~N> for u~N {
+                    #[inline(always)]
+                    fn from(value: ux::u~N) -> Self {
+                        Self::new(<$storage>::from(value))
+                    }
+                }
+
+                impl From<u~N> for ux::u~N {
+                    #[inline(always)]
+                    fn from(value: u~N) -> Self {
+                        ux::u~N::new(value.value())
+                    }
+                }
+            });
+        )*
+    };
+}
+
+#[cfg(feature = "ux")]
+impl_ux_conversions!(u8 in 1..8, u16 in 9..16, u32 in 17..32, u64 in 33..64);
+
 /// Trait for signed integer types.
 #[diagnostic::on_unimplemented(
     message = "`{Self}` is not a supported signed integer type",
@@ -470,6 +931,24 @@ const fn signed_mask(bits: usize) -> i64 {
     unsigned_mask(bits) as i64
 }
 
+/// Sign-extends the lowest `width` bits of `value` to a full [`i64`], i.e. interprets them as a
+/// `width`-bit two's complement integer. Bits above `width` are ignored.
+///
+/// A `width` of zero always yields zero.
+///
+/// # Panics
+/// Panics if `width` is greater than 64.
+#[inline(always)]
+pub const fn sign_extend_window(value: i64, width: u8) -> i64 {
+    assert!(width <= 64, "window width must be at most 64 bits");
+    if width == 0 {
+        return 0;
+    }
+
+    let rem = 64 - width as u32;
+    (value << rem) >> rem
+}
+
 /// A signed integer with `LEN` bits.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(
@@ -479,14 +958,14 @@ const fn signed_mask(bits: usize) -> i64 {
 #[repr(transparent)]
 pub struct SInt<T, const LEN: usize>(T);
 
-impl<T: std::fmt::Debug, const LEN: usize> std::fmt::Debug for SInt<T, LEN> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::Debug, const LEN: usize> core::fmt::Debug for SInt<T, LEN> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl<T: std::fmt::UpperHex, const LEN: usize> std::fmt::UpperHex for SInt<T, LEN> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::UpperHex, const LEN: usize> core::fmt::UpperHex for SInt<T, LEN> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -497,12 +976,8 @@ where
 {
     #[inline(always)]
     pub fn new(value: T) -> Self {
-        let masked = value & T::new(const { signed_mask(LEN) });
-
-        let rem = T::BITS - LEN;
-        let sign_extended = (masked << rem) >> rem;
-
-        Self(sign_extended)
+        let sign_extended = sign_extend_window(<T as SignedInt>::value(value), LEN as u8);
+        Self(<T as SignedInt>::new(sign_extended))
     }
 
     #[inline(always)]
@@ -512,12 +987,89 @@ where
         let max = const { signed_mask(LEN - 1) };
         let min = const { !signed_mask(LEN - 1) };
 
-        unsafe { std::hint::assert_unchecked(value <= T::new(max)) };
-        unsafe { std::hint::assert_unchecked(value >= T::new(min)) };
+        debug_assert!(
+            value <= T::new(max) && value >= T::new(min),
+            "invariant violated: value {} does not fit within {LEN} signed bits",
+            <T as SignedInt>::value(value),
+        );
+
+        unsafe { core::hint::assert_unchecked(value <= T::new(max)) };
+        unsafe { core::hint::assert_unchecked(value >= T::new(min)) };
         value
     }
+
+    /// Adds `rhs` to this value, saturating at the bounds of a `LEN`-bit signed integer instead
+    /// of overflowing.
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        let max = const { signed_mask(LEN - 1) };
+        let min = const { !signed_mask(LEN - 1) };
+
+        let sum = <T as SignedInt>::value(self.value()) + <T as SignedInt>::value(rhs.value());
+        Self(<T as SignedInt>::new(sum.clamp(min, max)))
+    }
+
+    /// Converts this value into a signed integer of the same bit width but a different storage
+    /// type, preserving its value.
+    #[inline(always)]
+    pub fn cast_storage<U>(self) -> SInt<U, LEN>
+    where
+        U: SignedInt + PrimInt + IsStorageForBits<LEN>,
+    {
+        SInt::new(<U as SignedInt>::new(<T as SignedInt>::value(self.value())))
+    }
+}
+
+impl<T, const LEN: usize> PartialEq<i64> for SInt<T, LEN>
+where
+    T: SignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn eq(&self, other: &i64) -> bool {
+        <T as SignedInt>::value(self.value()) == *other
+    }
+}
+
+impl<T, const LEN: usize> PartialOrd<i64> for SInt<T, LEN>
+where
+    T: SignedInt + PrimInt + IsStorageForBits<LEN>,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &i64) -> Option<core::cmp::Ordering> {
+        <T as SignedInt>::value(self.value()).partial_cmp(other)
+    }
 }
 
+// comparisons against i64 are covered by the impls above, and a separate impl for i64 storage
+// would conflict with them
+macro_rules! impl_sint_cmp_storage {
+    ($($storage:ty),*) => {
+        $(
+            impl<const LEN: usize> PartialEq<$storage> for SInt<$storage, LEN>
+            where
+                $storage: IsStorageForBits<LEN>,
+            {
+                #[inline(always)]
+                fn eq(&self, other: &$storage) -> bool {
+                    self.value() == *other
+                }
+            }
+
+            impl<const LEN: usize> PartialOrd<$storage> for SInt<$storage, LEN>
+            where
+                $storage: IsStorageForBits<LEN>,
+            {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &$storage) -> Option<core::cmp::Ordering> {
+                    self.value().partial_cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_sint_cmp_storage!(i8, i16, i32);
+
 impl<T, const LEN: usize> BitUtils for SInt<T, LEN>
 where
     T: SignedInt + PrimInt + BitUtils + IsStorageForBits<LEN>,
@@ -739,3 +1291,20 @@ seq!(N in 33..64 {
 
 #[allow(non_camel_case_types)]
 pub type i64 = sealed::I64;
+
+// wider storages can also hold signed integers of narrower widths, so that values can be moved
+// between storages with `SInt::cast_storage`
+seq!(N in 1..8 {
+    impl IsStorageForBits<N> for i16 {}
+    impl IsStorageForBits<N> for i32 {}
+    impl IsStorageForBits<N> for i64 {}
+});
+
+seq!(N in 9..16 {
+    impl IsStorageForBits<N> for i32 {}
+    impl IsStorageForBits<N> for i64 {}
+});
+
+seq!(N in 17..32 {
+    impl IsStorageForBits<N> for i64 {}
+});
@@ -1,6 +1,10 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod chunks;
 pub mod integer;
 
-use integer::{IsStorageForBits, SInt, UInt, UnsignedInt};
+use bitut::BitUtils;
+use integer::{Concat, IsStorageForBits, PackedArray, SInt, UInt, UnsignedInt};
 
 /// Trait for types that can try to be created from and turned into raw bits.
 pub trait TryBits: Sized {
@@ -14,12 +18,259 @@ pub trait TryBits: Sized {
     fn to_bits(&self) -> Self::Bits;
 }
 
+/// Trait for custom encodings of a field's value into raw bits, for field types which can't (or
+/// shouldn't) implement [`Bits`] themselves. Used through `#[bits(.., codec = C)]`.
+pub trait FieldCodec<T> {
+    /// The raw bits type values are encoded into.
+    type Bits: UnsignedInt + crate::Bits;
+
+    /// Encodes a value into its raw bit representation.
+    fn encode(value: T) -> Self::Bits;
+
+    /// Decodes a value from its raw bit representation.
+    fn decode(bits: Self::Bits) -> T;
+}
+
+/// Returns the number of bytes needed to hold `bits` bits.
+#[inline(always)]
+pub const fn byte_len(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+/// Returns whether the bit at `index` of `value` is set. Usable in const contexts.
+///
+/// Panics if `index` is not smaller than 64.
+#[inline(always)]
+pub const fn bit_at(value: u64, index: u8) -> bool {
+    assert!(index < 64, "bit index out of range");
+    (value >> index) & 1 == 1
+}
+
+/// Error returned when parsing a bitstruct from a comma separated list of `field=value` pairs
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFieldsError {
+    /// A pair was not of the form `field=value`.
+    Malformed,
+    /// A field name did not match any field of the bitstruct.
+    UnknownField,
+    /// A field value could not be parsed as an integer.
+    InvalidValue,
+    /// A field value did not fit within its field.
+    ValueDoesNotFit,
+}
+
+impl core::fmt::Display for ParseFieldsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::Malformed => "expected a pair of the form `field=value`",
+            Self::UnknownField => "unknown field",
+            Self::InvalidValue => "invalid field value",
+            Self::ValueDoesNotFit => "field value does not fit within the field",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFieldsError {}
+
+/// Unified error type covering every fallible operation of bitos, for code which would rather
+/// handle a single error type. The specific error types convert into it, so `?` can be used
+/// directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitosError {
+    /// A value did not fit within the bit width of its target.
+    ValueDoesNotFit {
+        /// The bit width of the target.
+        width: u8,
+    },
+    /// Raw bits did not hold a valid bit pattern for the target type, e.g. an enum discriminant
+    /// without a matching variant.
+    InvalidBitPattern,
+    /// A value was outside of the declared range of its field.
+    OutOfRange {
+        /// The raw value.
+        value: u64,
+        /// The smallest raw value in the range.
+        min: u64,
+        /// The largest raw value in the range.
+        max: u64,
+    },
+    /// An index was out of bounds of an array field.
+    IndexOutOfBounds {
+        /// The index that was accessed.
+        index: usize,
+        /// The length of the array field.
+        len: usize,
+    },
+    /// Parsing a bitstruct from a list of `field=value` pairs failed.
+    ParseFields(ParseFieldsError),
+}
+
+impl core::fmt::Display for BitosError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ValueDoesNotFit { width } => {
+                write!(f, "value does not fit in the target integer width {width}")
+            }
+            Self::OutOfRange { value, min, max } => {
+                write!(f, "value {value} is outside of the range {min}..={max}")
+            }
+            Self::InvalidBitPattern => f.write_str("invalid bit pattern"),
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} is out of bounds for length {len}")
+            }
+            Self::ParseFields(err) => write!(f, "failed to parse fields: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitosError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ParseFields(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<integer::ValueDoesNotFitErr> for BitosError {
+    #[inline(always)]
+    fn from(value: integer::ValueDoesNotFitErr) -> Self {
+        Self::ValueDoesNotFit {
+            width: value.width(),
+        }
+    }
+}
+
+impl From<ParseFieldsError> for BitosError {
+    #[inline(always)]
+    fn from(value: ParseFieldsError) -> Self {
+        Self::ParseFields(value)
+    }
+}
+
+/// Tries to create a value of type `T` from its raw bits, returning
+/// [`BitosError::InvalidBitPattern`] if they do not hold a valid bit pattern.
+#[inline(always)]
+pub fn from_bits_checked<T: TryBits>(bits: T::Bits) -> Result<T, BitosError> {
+    T::try_from_bits(bits).ok_or(BitosError::InvalidBitPattern)
+}
+
+/// Parses an unsigned integer field value. Supports decimal values as well as hexadecimal,
+/// octal and binary values prefixed with `0x`, `0o` and `0b` respectively.
+pub fn parse_field_value(s: &str) -> Option<u64> {
+    let (digits, radix) = if let Some(digits) = s.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = s.strip_prefix("0o") {
+        (digits, 8)
+    } else if let Some(digits) = s.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        (s, 10)
+    };
+
+    u64::from_str_radix(digits, radix).ok()
+}
+
+/// Policy for handling raw bits which do not correspond to any variant of a bit enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownPolicy<T> {
+    /// Unknown values are rejected with [`BitosError::InvalidBitPattern`].
+    Strict,
+    /// Unknown values are replaced with the given value.
+    Fallback(T),
+    /// Unknown values are clamped to the variant with the closest lower discriminant, or the
+    /// variant with the lowest discriminant if there's none.
+    Clamp,
+}
+
 /// Trait for types that can be created from and turned into raw bits.
 pub trait Bits: TryBits {
     /// Creates a value of this type from it's raw bit representation.
     fn from_bits(value: Self::Bits) -> Self;
 }
 
+/// Trait implemented by every struct generated by `#[bitos]`, for code which is generic over
+/// register types.
+///
+/// `BITS` matches [`BitWidth::BITS`], which is also implemented for every struct. On a concrete
+/// struct with both traits in scope, read it as `<T as BitosStruct>::BITS`.
+pub trait BitosStruct: Bits {
+    /// The bit width of this struct, as given to `#[bitos(..)]`.
+    const BITS: usize;
+
+    /// The integer backing this struct.
+    type Storage: UnsignedInt;
+}
+
+/// Trait for types with a known bit width. Implemented for all [`TryBits`] types.
+///
+/// On integer types `BITS` shares its name with [`UnsignedInt::BITS`] and the inherent `BITS` of
+/// primitives, so it has to be read as `<T as BitWidth>::BITS` there.
+pub trait BitWidth {
+    /// The bit width of this type.
+    const BITS: usize;
+}
+
+impl<T: TryBits> BitWidth for T {
+    const BITS: usize = <T::Bits as UnsignedInt>::BITS;
+}
+
+/// Extracts a field of type `F` stored in bits `start..end` of `inner`. The extracted bits are
+/// masked to fit within the raw bits type of `F`.
+///
+/// Together with [`insert_field`], this is what the accessors generated by `#[bitos]` use, and it
+/// can be used directly to work with fields of plain integers:
+///
+/// ```
+/// use bitos_core::{extract_field, insert_field, integer::u4};
+///
+/// let raw = 0xABCDu16;
+/// let nibble: u4 = extract_field(raw, 4, 8);
+/// assert_eq!(nibble, u4::new(0xC));
+///
+/// let raw = insert_field(raw, 4, 8, u4::new(0x5));
+/// assert_eq!(raw, 0xAB5D);
+/// ```
+#[inline(always)]
+pub fn extract_field<Inner, F>(inner: Inner, start: u8, end: u8) -> F
+where
+    Inner: UnsignedInt + BitUtils,
+    F: Bits,
+{
+    let bits = inner.bits(start, end).value();
+    F::from_bits(<F::Bits as UnsignedInt>::new(bits))
+}
+
+/// Tries to extract a field of type `F` stored in bits `start..end` of `inner`. Returns [`None`]
+/// if the extracted bits are not a valid value of `F`.
+#[inline(always)]
+pub fn try_extract_field<Inner, F>(inner: Inner, start: u8, end: u8) -> Option<F>
+where
+    Inner: UnsignedInt + BitUtils,
+    F: TryBits,
+{
+    let bits = inner.bits(start, end).value();
+    F::try_from_bits(<F::Bits as UnsignedInt>::new(bits))
+}
+
+/// Returns `inner` with bits `start..end` replaced by the raw bits of `value`. Bits of `value`
+/// which do not fit within `start..end` are discarded.
+#[inline(always)]
+pub fn insert_field<Inner, F>(inner: Inner, start: u8, end: u8, value: F) -> Inner
+where
+    Inner: UnsignedInt + BitUtils,
+    F: TryBits,
+{
+    let bits = Inner::new(value.to_bits().value());
+    inner.with_bits(start, end, bits)
+}
+
 macro_rules! impl_bits_uint {
     ($($prim:ty),*) => {
         $(
@@ -154,3 +405,146 @@ impl Bits for bool {
         value.value() == 1
     }
 }
+
+/// Arrays are packed with element 0 in the lowest bits.
+impl<B, const N: usize> TryBits for [B; N]
+where
+    B: TryBits,
+    B::Bits: PackedArray<N>,
+{
+    type Bits = <B::Bits as PackedArray<N>>::Packed;
+
+    #[inline(always)]
+    fn try_from_bits(value: Self::Bits) -> Option<Self> {
+        let raw = value.value();
+        let width = <B::Bits as UnsignedInt>::BITS;
+
+        let elements: [Option<B>; N] = core::array::from_fn(|i| {
+            B::try_from_bits(<B::Bits as UnsignedInt>::new(raw >> (i * width)))
+        });
+
+        if elements.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(elements.map(|e| unsafe { e.unwrap_unchecked() }))
+    }
+
+    #[inline(always)]
+    fn to_bits(&self) -> Self::Bits {
+        let width = <B::Bits as UnsignedInt>::BITS;
+        let raw = self.iter().enumerate().fold(0u64, |acc, (i, e)| {
+            acc | (e.to_bits().value() << (i * width))
+        });
+
+        Self::Bits::new(raw)
+    }
+}
+
+impl<B, const N: usize> Bits for [B; N]
+where
+    B: Bits,
+    B::Bits: PackedArray<N>,
+{
+    #[inline(always)]
+    fn from_bits(value: Self::Bits) -> Self {
+        let raw = value.value();
+        let width = <B::Bits as UnsignedInt>::BITS;
+
+        core::array::from_fn(|i| B::from_bits(<B::Bits as UnsignedInt>::new(raw >> (i * width))))
+    }
+}
+
+/// The raw bits type of a tuple, concatenating the raw bits of its elements.
+macro_rules! tuple_bits {
+    ($t:ident) => {
+        <$t as TryBits>::Bits
+    };
+    ($head:ident, $($rest:ident),+) => {
+        <<$head as TryBits>::Bits as Concat<tuple_bits!($($rest),+)>>::Output
+    };
+}
+
+/// Tuples are packed with element 0 in the lowest bits.
+macro_rules! impl_bits_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl_bits_tuple!(@bounds [] [$($t $i),+] $($t),+);
+    };
+    (@bounds [$($bounds:tt)*] $all:tt $last:ident) => {
+        impl_bits_tuple!(@impl [$($bounds)*] $all);
+    };
+    (@bounds [$($bounds:tt)*] $all:tt $head:ident, $($rest:ident),+) => {
+        impl_bits_tuple!(
+            @bounds
+            [$($bounds)* <$head as TryBits>::Bits: Concat<tuple_bits!($($rest),+)>,]
+            $all
+            $($rest),+
+        );
+    };
+    (@impl [$($bounds:tt)*] [$($t:ident $i:tt),+]) => {
+        impl<$($t: TryBits),+> TryBits for ($($t,)+)
+        where
+            $($bounds)*
+        {
+            type Bits = tuple_bits!($($t),+);
+
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn try_from_bits(value: Self::Bits) -> Option<Self> {
+                let raw = value.value();
+                let mut offset = 0;
+
+                Some(($(
+                    {
+                        let bits = <$t::Bits as UnsignedInt>::new(raw >> offset);
+                        offset += <$t::Bits as UnsignedInt>::BITS;
+                        $t::try_from_bits(bits)?
+                    },
+                )+))
+            }
+
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn to_bits(&self) -> Self::Bits {
+                let mut raw = 0u64;
+                let mut offset = 0;
+
+                $(
+                    raw |= self.$i.to_bits().value() << offset;
+                    offset += <$t::Bits as UnsignedInt>::BITS;
+                )+
+
+                Self::Bits::new(raw)
+            }
+        }
+
+        impl<$($t: Bits),+> Bits for ($($t,)+)
+        where
+            $($bounds)*
+        {
+            #[inline(always)]
+            #[allow(unused_assignments)]
+            fn from_bits(value: Self::Bits) -> Self {
+                let raw = value.value();
+                let mut offset = 0;
+
+                ($(
+                    {
+                        let bits = <$t::Bits as UnsignedInt>::new(raw >> offset);
+                        offset += <$t::Bits as UnsignedInt>::BITS;
+                        $t::from_bits(bits)
+                    },
+                )+)
+            }
+        }
+    };
+}
+
+impl_bits_tuple!(A 0);
+impl_bits_tuple!(A 0, B 1);
+impl_bits_tuple!(A 0, B 1, C 2);
+impl_bits_tuple!(A 0, B 1, C 2, D 3);
+impl_bits_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_bits_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_bits_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_bits_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
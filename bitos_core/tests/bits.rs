@@ -0,0 +1,40 @@
+use bitos_core::{
+    Bits, TryBits, bit_at,
+    integer::{u3, u4, u12},
+};
+
+const _: () = assert!(bit_at(1, 0));
+const _: () = assert!(!bit_at(1 << 1, 0));
+const _: () = assert!(bit_at(1 << 31, 31));
+const _: () = assert!(!bit_at(u32::MAX as u64 >> 1, 31));
+const _: () = assert!(bit_at(1 << 63, 63));
+const _: () = assert!(!bit_at(u64::MAX >> 1, 63));
+
+#[test]
+fn arrays_pack_elements_from_the_lowest_bits() {
+    let nibbles = [u4::new(0x3), u4::new(0xA)];
+    assert_eq!(nibbles.to_bits(), 0xA3u8);
+    assert_eq!(<[u4; 2]>::from_bits(0xA3), nibbles);
+
+    let flags = [true, false, false, true, false, false, false, true];
+    assert_eq!(flags.to_bits(), 0b1000_1001u8);
+    assert_eq!(<[bool; 8]>::from_bits(0b1000_1001), flags);
+    assert_eq!(<[bool; 8]>::try_from_bits(0xFF), Some([true; 8]));
+}
+
+#[test]
+fn tuples_pack_element_zero_into_the_lowest_bits() {
+    let pair = (u4::new(0x5), u12::new(0xABC));
+    assert_eq!(pair.to_bits(), 0xABC5u16);
+    assert_eq!(<(u4, u12)>::from_bits(0xABC5), pair);
+
+    let triple = (true, u3::new(0b010), u4::new(0xF));
+    assert_eq!(triple.to_bits(), 0xF5u8);
+    assert_eq!(<(bool, u3, u4)>::try_from_bits(0xF5), Some(triple));
+}
+
+#[test]
+#[should_panic(expected = "bit index out of range")]
+fn bit_at_rejects_indices_past_63() {
+    std::hint::black_box(bit_at(0, std::hint::black_box(64)));
+}
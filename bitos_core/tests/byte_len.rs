@@ -0,0 +1,13 @@
+use bitos_core::byte_len;
+
+const _: () = assert!(byte_len(1) == 1);
+const _: () = assert!(byte_len(8) == 1);
+const _: () = assert!(byte_len(9) == 2);
+const _: () = assert!(byte_len(12) == 2);
+const _: () = assert!(byte_len(64) == 8);
+
+#[test]
+fn byte_len_can_size_arrays() {
+    let bytes = [0u8; byte_len(12)];
+    assert_eq!(bytes.len(), 2);
+}
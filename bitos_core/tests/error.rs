@@ -0,0 +1,65 @@
+use bitos_core::{BitosError, ParseFieldsError, integer::ValueDoesNotFitErr};
+
+#[test]
+fn specific_errors_convert_into_bitos_error() {
+    assert_eq!(
+        BitosError::from(ValueDoesNotFitErr::new(12)),
+        BitosError::ValueDoesNotFit { width: 12 }
+    );
+    assert_eq!(
+        BitosError::from(ParseFieldsError::UnknownField),
+        BitosError::ParseFields(ParseFieldsError::UnknownField)
+    );
+}
+
+#[test]
+fn errors_are_displayed() {
+    assert_eq!(
+        BitosError::IndexOutOfBounds { index: 4, len: 4 }.to_string(),
+        "index 4 is out of bounds for length 4"
+    );
+    assert_eq!(
+        BitosError::OutOfRange {
+            value: 12,
+            min: 2,
+            max: 11
+        }
+        .to_string(),
+        "value 12 is outside of the range 2..=11"
+    );
+    assert_eq!(
+        BitosError::ParseFields(ParseFieldsError::Malformed).to_string(),
+        "failed to parse fields: expected a pair of the form `field=value`"
+    );
+}
+
+#[test]
+fn value_does_not_fit_errors_are_displayed() {
+    assert_eq!(
+        ValueDoesNotFitErr::new(12).to_string(),
+        "value does not fit in the target integer width 12"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn errors_box_into_dyn_error() {
+    let err: Box<dyn std::error::Error> = ValueDoesNotFitErr::new(12).into();
+    assert_eq!(
+        err.to_string(),
+        "value does not fit in the target integer width 12"
+    );
+
+    let err: Box<dyn std::error::Error> = BitosError::InvalidBitPattern.into();
+    assert_eq!(err.to_string(), "invalid bit pattern");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_errors_are_the_source() {
+    use std::error::Error;
+
+    let err = BitosError::ParseFields(ParseFieldsError::InvalidValue);
+    assert_eq!(err.source().unwrap().to_string(), "invalid field value");
+    assert!(BitosError::InvalidBitPattern.source().is_none());
+}
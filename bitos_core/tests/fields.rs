@@ -0,0 +1,47 @@
+use bitos_core::{
+    extract_field, insert_field,
+    integer::{u4, u12, u24},
+    try_extract_field,
+};
+
+#[test]
+fn fields_spanning_byte_boundaries() {
+    let raw = 0x1234_5678u32;
+
+    assert_eq!(extract_field::<u32, u12>(raw, 4, 16), u12::new(0x567));
+    assert_eq!(extract_field::<u32, u8>(raw, 12, 20), 0x45);
+    assert_eq!(insert_field(raw, 12, 20, 0xAAu8), 0x123A_A678);
+}
+
+#[test]
+fn fields_at_the_edges_of_the_storage() {
+    assert_eq!(extract_field::<u8, u4>(0xA5, 4, 8), u4::new(0xA));
+    assert_eq!(extract_field::<u8, u8>(0xA5, 0, 8), 0xA5);
+    assert_eq!(insert_field(0xA5u8, 0, 8, 0x5Au8), 0x5A);
+
+    let raw = 0x8000_0000_0000_0001u64;
+    assert!(extract_field::<u64, bool>(raw, 63, 64));
+    assert_eq!(extract_field::<u64, u64>(raw, 0, 64), raw);
+    assert_eq!(
+        insert_field(raw, 60, 64, u4::new(0x7)),
+        0x7000_0000_0000_0001
+    );
+    assert_eq!(insert_field(0u64, 0, 64, u64::MAX), u64::MAX);
+}
+
+#[test]
+fn fields_within_non_primitive_storage() {
+    let raw = u24::new(0xABCDEF);
+
+    assert_eq!(extract_field::<u24, u12>(raw, 12, 24), u12::new(0xABC));
+    assert_eq!(
+        insert_field(raw, 0, 12, u12::new(0x123)),
+        u24::new(0xABC123)
+    );
+}
+
+#[test]
+fn inserted_values_wider_than_the_field_are_truncated() {
+    assert_eq!(insert_field(0u16, 4, 8, 0xFFu8), 0x00F0);
+    assert_eq!(try_extract_field::<u16, u8>(0x0FF0, 4, 12), Some(0xFF));
+}
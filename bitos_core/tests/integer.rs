@@ -0,0 +1,271 @@
+use bitos_core::integer::{
+    SInt, ValueDoesNotFitErr, i4, i12, mask_to_width, range_mask, sign_extend_window, u1, u3, u4,
+    u12, u20, u40,
+};
+use bitut::BitUtils;
+
+const _: () = assert!(mask_to_width(u64::MAX, 0) == 0);
+const _: () = assert!(mask_to_width(u64::MAX, 1) == 1);
+const _: () = assert!(mask_to_width(0b1011, 1) == 1);
+const _: () = assert!(mask_to_width(u64::MAX, 63) == i64::MAX as u64);
+const _: () = assert!(mask_to_width(u64::MAX, 64) == u64::MAX);
+const _: () = assert!(mask_to_width(0x8000_0000_0000_0001, 64) == 0x8000_0000_0000_0001);
+
+const _: () = assert!(range_mask(0, 64) == u64::MAX);
+const _: () = assert!(range_mask(4, 8) == 0xF0);
+const _: () = assert!(range_mask(60, 70) == 0xF000_0000_0000_0000);
+
+const fn sign_extends_every_width() -> bool {
+    let mut width = 1;
+    while width <= 64 {
+        let negative = -1i64 << (width - 1);
+        let above = if width == 64 { 0 } else { -1i64 << width };
+
+        // the top bit of the window is set, everything above it is garbage
+        let value = negative & !above | above & 0x5555_5555_5555_5555;
+        if sign_extend_window(value, width as u8) != negative {
+            return false;
+        }
+
+        // the largest positive value is kept as is
+        let positive = !negative;
+        if sign_extend_window(positive | above, width as u8) != positive {
+            return false;
+        }
+
+        width += 1;
+    }
+
+    true
+}
+
+const _: () = assert!(sign_extends_every_width());
+const _: () = assert!(sign_extend_window(-1, 64) == -1);
+const _: () = assert!(sign_extend_window(i64::MIN, 64) == i64::MIN);
+const _: () = assert!(sign_extend_window(0b1, 1) == -1);
+const _: () = assert!(sign_extend_window(0x7ff, 12) == 0x7ff);
+const _: () = assert!(sign_extend_window(0xfff, 0) == 0);
+
+#[test]
+fn sign_extension_ignores_bits_above_the_window() {
+    assert_eq!(sign_extend_window(0xf0f, 4), -1);
+    assert_eq!(sign_extend_window(0xf07, 4), 7);
+}
+
+#[test]
+#[should_panic]
+fn sign_extension_rejects_windows_wider_than_64() {
+    std::hint::black_box(sign_extend_window(0, std::hint::black_box(65)));
+}
+
+// `UInt` and `SInt` are `repr(transparent)`, so a corrupted value can be forged by transmuting
+// its storage. the invariant check must catch it before `value` hands it out
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "does not fit within 4 bits")]
+fn corrupted_unsigned_values_are_caught() {
+    let corrupted = unsafe { std::mem::transmute::<u8, u4>(0xFF) };
+    std::hint::black_box(corrupted.value());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "does not fit within 4 signed bits")]
+fn corrupted_signed_values_are_caught() {
+    let corrupted = unsafe { std::mem::transmute::<i8, i4>(0x7F) };
+    std::hint::black_box(corrupted.value());
+}
+
+#[test]
+fn primitives_convert_into_wider_integers() {
+    assert_eq!(u12::from(200u8), u12::new(200));
+    assert_eq!(u40::from(u32::MAX), u40::new(u32::MAX as u64));
+    assert_eq!(u1::from(true), u1::new(1));
+    assert_eq!(u1::from(false), u1::new(0));
+}
+
+#[test]
+fn primitives_try_convert_into_narrower_integers() {
+    assert_eq!(u12::try_from(0xFFFu16), Ok(u12::new(0xFFF)));
+    assert_eq!(u12::try_from(0x1000u16), Err(ValueDoesNotFitErr::new(12)));
+    assert_eq!(u4::try_from(16u32), Err(ValueDoesNotFitErr::new(4)));
+}
+
+#[test]
+fn masks_cover_the_requested_bits() {
+    assert_eq!(u12::mask(0, 12), u12::new(0xFFF));
+    assert_eq!(u12::mask(4, 8), u12::new(0x0F0));
+    assert_eq!(u12::mask(8, 64), u12::new(0xF00));
+    assert_eq!(u12::mask(3, 3), u12::new(0));
+}
+
+#[test]
+fn bits_are_reversed_within_the_width() {
+    assert_eq!(u3::new(0b001).reverse_bits(), u3::new(0b100));
+    assert_eq!(u3::new(0b110).reverse_bits(), u3::new(0b011));
+    assert_eq!(u12::new(0x001).reverse_bits(), u12::new(0x800));
+    assert_eq!(u12::new(0xA5F).reverse_bits(), u12::new(0xFA5));
+    assert_eq!(u1::new(1).reverse_bits(), u1::new(1));
+    assert_eq!(u4::new(0b0001).reverse_bits(), u4::new(0b1000));
+    assert_eq!(u4::new(0b0110).reverse_bits(), u4::new(0b0110));
+}
+
+#[test]
+fn gray_codes_round_trip() {
+    assert_eq!(u3::new(0b010).to_gray(), u3::new(0b011));
+    assert_eq!(u3::new(0b111).to_gray(), u3::new(0b100));
+
+    for raw in 0..=0xFFF {
+        let value = u12::new(raw);
+        let gray = value.to_gray();
+        assert_eq!(gray.from_gray(), value);
+
+        // consecutive values differ by a single bit once encoded
+        if raw < 0xFFF {
+            let next = u12::new(raw + 1).to_gray();
+            assert_eq!((gray.value() ^ next.value()).count_ones(), 1);
+        }
+    }
+}
+
+#[test]
+fn signed_values_convert_only_when_non_negative_and_fitting() {
+    assert_eq!(u12::try_from_signed(0), Some(u12::new(0)));
+    assert_eq!(u12::try_from_signed(0xFFF), Some(u12::new(0xFFF)));
+    assert_eq!(u12::try_from_signed(0x1000), None);
+    assert_eq!(u12::try_from_signed(-1), None);
+    assert_eq!(u40::try_from_signed(i64::MIN), None);
+}
+
+#[test]
+fn casting_signed_storage_preserves_the_value() {
+    let wide: SInt<i16, 4> = i4::new(-1).cast_storage();
+    assert_eq!(wide.value(), -1);
+    assert_eq!(i4::new(-8).cast_storage::<i16>().value(), -8);
+    assert_eq!(i4::new(7).cast_storage::<i16>().value(), 7);
+
+    let wider: SInt<i32, 4> = i4::new(-8).cast_storage();
+    assert_eq!(wider.value(), -8);
+    assert_eq!(i4::new(-1).cast_storage::<i32>().value(), -1);
+
+    // and back into the narrowest storage
+    assert_eq!(wider.cast_storage::<i8>(), i4::new(-8));
+    assert_eq!(i12::new(-0x800).cast_storage::<i64>().value(), -0x800);
+}
+
+#[test]
+fn increments_wrap_around_the_width() {
+    assert_eq!(u4::new(14).wrapping_increment(), u4::new(15));
+    assert_eq!(u4::new(15).wrapping_increment(), u4::new(0));
+    assert_eq!(u4::new(0).wrapping_decrement(), u4::new(15));
+    assert_eq!(u4::new(1).wrapping_decrement(), u4::new(0));
+
+    assert_eq!(u12::new(0xFFF).wrapping_increment(), u12::new(0));
+    assert_eq!(u12::new(0).wrapping_decrement(), u12::new(0xFFF));
+    assert_eq!(u12::new(0x7FF).wrapping_increment(), u12::new(0x800));
+}
+
+#[test]
+fn powers_wrap_or_overflow_past_the_width() {
+    assert_eq!(u4::new(2).pow(3), u4::new(8));
+    assert_eq!(u4::new(2).checked_pow(3), Some(u4::new(8)));
+    assert_eq!(u4::new(3).pow(0), u4::new(1));
+
+    // 2^4 doesn't fit within 4 bits
+    assert_eq!(u4::new(2).pow(4), u4::new(0));
+    assert_eq!(u4::new(2).checked_pow(4), None);
+    assert_eq!(u4::new(3).pow(3), u4::new(27 % 16));
+    assert_eq!(u4::new(3).checked_pow(3), None);
+
+    assert_eq!(u12::new(2).pow(11), u12::new(0x800));
+    assert_eq!(u12::new(2).checked_pow(12), None);
+}
+
+#[test]
+fn powers_of_two_are_bounded_by_the_width() {
+    assert!(u4::new(8).is_power_of_two());
+    assert!(u4::new(1).is_power_of_two());
+    assert!(!u4::new(9).is_power_of_two());
+    assert!(!u4::new(0).is_power_of_two());
+
+    assert_eq!(u4::new(7).next_power_of_two(), u4::new(8));
+    assert_eq!(u4::new(8).next_power_of_two(), u4::new(8));
+    assert_eq!(u4::new(8).checked_next_power_of_two(), Some(u4::new(8)));
+    assert_eq!(u12::new(300).next_power_of_two(), u12::new(512));
+
+    // 16 doesn't fit within 4 bits
+    assert_eq!(u4::new(9).next_power_of_two(), u4::new(0));
+    assert_eq!(u4::new(9).checked_next_power_of_two(), None);
+    assert_eq!(u4::new(15).checked_next_power_of_two(), None);
+}
+
+#[test]
+fn integers_convert_into_wider_primitives() {
+    // the smallest fitting primitive
+    assert_eq!(u8::from(u4::new(0xA)), 0xA);
+    assert_eq!(u16::from(u12::new(0xABC)), 0xABC);
+    assert_eq!(u32::from(u20::new(0xA_BCDE)), 0xA_BCDE);
+    assert_eq!(u64::from(u40::new(0xAB_CDEF_0123)), 0xAB_CDEF_0123);
+
+    // wider primitives
+    assert_eq!(u16::from(u4::new(0xF)), 0xF);
+    assert_eq!(u32::from(u4::new(0xF)), 0xF);
+    assert_eq!(u64::from(u4::new(0xF)), 0xF);
+    assert_eq!(u32::from(u12::new(0xFFF)), 0xFFF);
+    assert_eq!(u64::from(u12::new(0xFFF)), 0xFFF);
+    assert_eq!(u64::from(u20::new(0xF_FFFF)), 0xF_FFFF);
+}
+
+#[test]
+fn unsigned_integers_compare_against_primitives() {
+    let value = u12::new(0x800);
+
+    assert!(value == 0x800u64);
+    assert!(value != 0x801u64);
+    assert!(value < 0x801u64 && value >= 0x800u64);
+    assert!(value > 0x7FFu64 && value <= 0x800u64);
+    // wider than the integer itself
+    assert!(value < 0x1000u64 && value != 0x1000u64);
+
+    assert!(value == 0x800u16);
+    assert!(value < 0x801u16 && value >= 0x800u16);
+    assert!(value > 0x7FFu16 && value <= 0x800u16);
+    assert!(value < u16::MAX);
+}
+
+#[test]
+fn signed_integers_compare_against_primitives() {
+    let value = i12::new(-0x800);
+
+    assert!(value == -0x800i64);
+    assert!(value != 0x800i64);
+    assert!(value < -0x7FFi64 && value >= -0x800i64);
+    assert!(value > -0x801i64 && value <= -0x800i64);
+    // wider than the integer itself
+    assert!(value > i64::MIN && value < 0x800i64);
+
+    assert!(value == -0x800i16);
+    assert!(value < -0x7FFi16 && value >= -0x800i16);
+    assert!(value > -0x801i16 && value <= -0x800i16);
+    assert!(value > i16::MIN);
+}
+
+#[test]
+fn fallible_bit_accessors_are_bounded_by_the_width() {
+    let value = u4::new(0b1000);
+
+    // the last bit of the value is accepted, the storage bits past it are not
+    assert_eq!(value.try_bit(3), Some(true));
+    assert_eq!(value.try_bit(4), None);
+    assert_eq!(value.try_bit(7), None);
+
+    assert_eq!(value.try_with_bit(3, false), Some(u4::new(0)));
+    assert_eq!(u4::new(0).try_with_bit(3, true), Some(value));
+    assert_eq!(value.try_with_bit(4, true), None);
+    assert_eq!(value.try_with_bit(4, false), None);
+
+    assert_eq!(value.try_bits(2, 4), Some(u4::new(0b10)));
+    assert_eq!(value.try_bits(2, 5), None);
+    assert_eq!(value.try_with_bits(0, 4, u4::new(0xF)), Some(u4::new(0xF)));
+    assert_eq!(value.try_with_bits(0, 5, u4::new(0xF)), None);
+}
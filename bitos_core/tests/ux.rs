@@ -0,0 +1,14 @@
+#![cfg(feature = "ux")]
+
+use bitos_core::integer::{u3, u12, u24, u40};
+
+#[test]
+fn ux_integers_round_trip() {
+    let value = u12::from(ux::u12::new(0xABC));
+    assert_eq!(value, u12::new(0xABC));
+    assert_eq!(ux::u12::from(value), ux::u12::new(0xABC));
+
+    assert_eq!(ux::u3::from(u3::new(5)), ux::u3::new(5));
+    assert_eq!(u24::from(ux::u24::MAX), u24::new(0xFF_FFFF));
+    assert_eq!(ux::u40::from(u40::new(1 << 39)), ux::u40::new(1 << 39));
+}
@@ -0,0 +1,38 @@
+use bitos::{
+    integer::{u12, u20},
+    prelude::*,
+};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+#[bitos(32)]
+#[derive(Debug, Clone, Copy)]
+pub struct Word {
+    #[bits(0..32)]
+    pub value: u32,
+}
+
+#[bitos(32)]
+#[derive(Debug, Clone, Copy)]
+pub struct Split {
+    #[bits(0..12)]
+    pub low: u12,
+    #[bits(12..)]
+    pub high: u20,
+}
+
+fn full_width(c: &mut Criterion) {
+    let word = Word::from_bits(0xDEAD_BEEF);
+    c.bench_function("full width get", |b| b.iter(|| black_box(word).value()));
+    c.bench_function("full width set", |b| {
+        b.iter(|| black_box(word).with_value(black_box(0xCAFE_BABE)))
+    });
+
+    let split = Split::from_bits(0xDEAD_BEEF);
+    c.bench_function("partial width get", |b| b.iter(|| black_box(split).high()));
+    c.bench_function("partial width set", |b| {
+        b.iter(|| black_box(split).with_high(black_box(u20::new(0xCAFE))))
+    });
+}
+
+criterion_group!(benches, full_width);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+use bitos::prelude::*;
+
+/// A temperature in degrees Celsius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Celsius(i16);
+
+/// Encodes temperatures from -40°C to 215°C as an unsigned byte offset by 40.
+pub struct OffsetCodec;
+
+impl FieldCodec<Celsius> for OffsetCodec {
+    type Bits = u8;
+
+    fn encode(value: Celsius) -> Self::Bits {
+        (value.0 + 40) as u8
+    }
+
+    fn decode(bits: Self::Bits) -> Celsius {
+        Celsius(bits as i16 - 40)
+    }
+}
+
+#[bitos(16)]
+#[derive(Debug)]
+pub struct Sensor {
+    #[bits(0..8, codec = OffsetCodec)]
+    temperature: Celsius,
+    #[bits(8..16)]
+    humidity: u8,
+}
+
+fn main() {
+    let sensor = Sensor::from_bits(0)
+        .with_temperature(Celsius(-15))
+        .with_humidity(60);
+
+    assert_eq!(sensor.to_bits(), 0x3C19);
+    assert_eq!(sensor.temperature(), Celsius(-15));
+    println!("{sensor:?}");
+}
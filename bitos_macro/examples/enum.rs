@@ -9,7 +9,63 @@ pub enum Kind {
     C,
 }
 
+#[bitos(2)]
+#[derive(Debug, PartialEq)]
+pub enum Speed {
+    Slow = 1,
+    Fast = 2,
+}
+
+#[bitos(8, parse)]
+#[derive(Debug)]
+pub struct Port {
+    #[bits(0..2, invalid = 0)]
+    speed: Option<Speed>,
+    #[bits(2..4)]
+    kind: Option<Kind>,
+    #[bits(4..8)]
+    id: u4,
+}
+
+fn decode(id: u64, speed: u8) -> Result<(u4, Speed), bitos::BitosError> {
+    let id = u4::try_from(id)?;
+    let speed = bitos::from_bits_checked::<Speed>(u2::new(speed))?;
+    Ok((id, speed))
+}
+
 fn main() {
+    assert_eq!(decode(3, 0b01), Ok((u4::new(3), Speed::Slow)));
+    assert_eq!(
+        decode(16, 0b01),
+        Err(bitos::BitosError::ValueDoesNotFit { width: 4 })
+    );
+    assert_eq!(decode(3, 0b11), Err(bitos::BitosError::InvalidBitPattern));
+
+    let err = bitos::BitosError::from("unknown=1".parse::<Port>().unwrap_err());
+    assert!(matches!(
+        err,
+        bitos::BitosError::ParseFields(bitos::ParseFieldsError::UnknownField)
+    ));
+
+    let err = bitos::BitosError::IndexOutOfBounds { index: 4, len: 4 };
+    assert_eq!(err.to_string(), "index 4 is out of bounds for length 4");
+
+    assert_eq!(Port::from_bits(0b0000_0100).speed(), None);
+    assert_eq!(Port::from_bits(0b0000_0110).speed(), Some(Speed::Fast));
+
+    let mut port = Port::from_bits(0b0000_0110);
+    port.set_speed(None);
+    assert_eq!((port.to_bits(), port.speed()), (0b0000_0100, None));
+    port.set_speed(Some(Speed::Slow));
+    assert_eq!(
+        (port.to_bits(), port.speed()),
+        (0b0000_0101, Some(Speed::Slow))
+    );
+
+    assert!(Port::from_bits(0b0000_0110).is_valid());
+    // valid speed, but 0b11 is not a valid kind
+    assert!(!Port::from_bits(0b0000_1110).is_valid());
+
     // let person = Person::<i7>::from_bits(0b100_0000);
     // assert_eq!(person.age().value(), 0b1100_0000u8 as i8)
 }
@@ -0,0 +1,25 @@
+use bitos::prelude::*;
+
+#[bitflags(8)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute = 7,
+}
+
+fn main() {
+    let mut permissions = PermissionFlags::empty().with(Permission::Read);
+    permissions.insert(Permission::Execute);
+
+    assert!(permissions.contains(Permission::Read));
+    assert!(!permissions.contains(Permission::Write));
+    assert_eq!(permissions.to_bits(), 0b1000_0001);
+
+    permissions.remove(Permission::Read);
+    assert_eq!(
+        permissions.union(Permission::Write.into()),
+        PermissionFlags::from_bits(0b1000_0010)
+    );
+
+    println!("{permissions:?}");
+}
@@ -0,0 +1,27 @@
+// Accessors of full width fields, exported so that their generated assembly can be inspected.
+
+use bitos::prelude::*;
+
+#[bitos(32)]
+#[derive(Debug, Clone, Copy)]
+pub struct Word {
+    #[bits(0..32)]
+    pub value: u32,
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn full_width_get(word: Word) -> u32 {
+    word.value()
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn full_width_set(word: Word, value: u32) -> Word {
+    word.with_value(value)
+}
+
+fn main() {
+    let word = full_width_set(Word::from_bits(0), 0xDEAD_BEEF);
+    assert_eq!(full_width_get(word), 0xDEAD_BEEF);
+}
@@ -0,0 +1,41 @@
+use bitos::{
+    BitUtils,
+    integer::{u4, u12, u20, u40},
+};
+
+fn main() {
+    let nibble: u8 = u4::new(0xA).into();
+    let wide: u16 = u12::new(0xABC).into();
+    let wider: u32 = u12::new(0xABC).into();
+    let word: u32 = u20::new(0xA_BCDE).into();
+    let long: u64 = u40::new(0xAB_CDEF_0123).into();
+    assert_eq!(
+        (nibble, wide, wider, word, long),
+        (0xA, 0xABC, 0xABC, 0xA_BCDE, 0xAB_CDEF_0123)
+    );
+
+    assert_eq!(u4::new(15).wrapping_increment(), u4::new(0));
+    assert_eq!(u4::new(0).wrapping_decrement(), u4::new(15));
+    assert_eq!(u12::new(0xFFF).wrapping_increment(), u12::new(0));
+    assert_eq!(u12::new(0).wrapping_decrement(), u12::new(0xFFF));
+    assert_eq!(u12::new(0x7FF).wrapping_increment(), u12::new(0x800));
+
+    assert_eq!(u4::new(2).pow(3), u4::new(8));
+    assert_eq!(u4::new(2).checked_pow(3), Some(u4::new(8)));
+    assert_eq!(u4::new(2).pow(4), u4::new(0));
+    assert_eq!(u4::new(2).checked_pow(4), None);
+
+    assert!(u4::new(8).is_power_of_two() && !u4::new(9).is_power_of_two());
+    assert_eq!(u4::new(7).next_power_of_two(), u4::new(8));
+    assert_eq!(u4::new(8).checked_next_power_of_two(), Some(u4::new(8)));
+    assert_eq!(u4::new(9).next_power_of_two(), u4::new(0));
+    assert_eq!(u4::new(9).checked_next_power_of_two(), None);
+    assert_eq!(u12::new(300).next_power_of_two(), u12::new(512));
+
+    assert_eq!(u4::new(9) + u4::new(9), u4::new(2));
+    assert_eq!(u4::new(1) - u4::new(2), u4::new(15));
+
+    assert_eq!(u4::new(0b1000).try_bit(3), Some(true));
+    assert_eq!(u4::new(0).try_bit(4), None);
+    assert_eq!(u4::new(0).try_with_bit(4, true), None);
+}
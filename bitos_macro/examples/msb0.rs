@@ -0,0 +1,23 @@
+use bitos::{integer::u4, prelude::*};
+
+/// An IPv4-style header word, with bits numbered from the most significant bit.
+#[bitos(16, msb0)]
+#[derive(Debug)]
+pub struct Header {
+    #[bits(0..4)]
+    version: u4,
+    #[bits(4..8)]
+    ihl: u4,
+    #[bits(8..16)]
+    tos: u8,
+}
+
+fn main() {
+    let header = Header::from_bits(0)
+        .with_version(u4::new(4))
+        .with_ihl(u4::new(5))
+        .with_tos(0xAB);
+
+    assert_eq!(header.to_bits(), 0x45AB);
+    println!("{header:?}");
+}
@@ -0,0 +1,60 @@
+use bitos::{
+    integer::{u3, u4, u12},
+    prelude::*,
+};
+
+#[bitos(8, field_id)]
+#[derive(Debug, Clone, Copy)]
+pub struct Flags {
+    #[bits(0..4)]
+    kind: u4,
+    #[bits(4)]
+    enabled: bool,
+    #[bits(5..8, reserved)]
+    _reserved: u3,
+}
+
+#[bitos(16, field_id)]
+#[derive(Debug, Clone, Copy)]
+pub struct Status {
+    #[bits(0..8, nested)]
+    flags: Flags,
+    #[bits(8..16)]
+    counter: u8,
+}
+
+#[bitos(32, field_id)]
+#[derive(Debug, Clone, Copy)]
+pub struct Register {
+    #[bits(0..12)]
+    address: u12,
+    #[bits(12..16, reserved)]
+    _reserved: u4,
+    #[bits(16..32, nested)]
+    status: Status,
+}
+
+fn main() {
+    let mut register = Register::from_bits(0);
+    register.set_status(
+        register.status().with_counter(0xAB).with_flags(
+            Flags::from_bits(0)
+                .with_kind(u4::new(0x3))
+                .with_enabled(true),
+        ),
+    );
+
+    assert_eq!(register.status().counter(), 0xAB);
+    assert_eq!(register.status().flags().kind(), u4::new(0x3));
+    assert!(register.status().flags().enabled());
+    assert_eq!(register.to_bits(), 0xAB13_0000);
+
+    assert_eq!(register.get_raw("status.flags.kind"), Some(0x3));
+    assert_eq!(register.get_raw("status.counter"), Some(0xAB));
+    assert_eq!(register.get_raw("address.kind"), None);
+
+    register.set_raw("status.flags.kind", 0x5).unwrap();
+    assert_eq!(register.status().flags().kind(), u4::new(0x5));
+
+    println!("{register:?}");
+}
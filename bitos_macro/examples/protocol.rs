@@ -0,0 +1,46 @@
+use bitos::{integer::u4, prelude::*};
+
+/// The first word of an IPv4 header, as laid out on the wire.
+#[bitos(32, from_be)]
+#[derive(Debug, Default, PartialEq)]
+pub struct Ipv4Word {
+    #[bits(0..16)]
+    total_length: u16,
+    #[bits(16..24)]
+    tos: u8,
+    #[bits(24..28, default = 5)]
+    ihl: u4,
+    #[bits(28..32, default = 4)]
+    version: u4,
+}
+
+fn main() {
+    let wire = [0x45, 0x00, 0x00, 0x54];
+    let word = Ipv4Word::from_be_bytes(wire);
+    assert_eq!(word, Ipv4Word::from_be_bits(u32::from_ne_bytes(wire)));
+    assert_eq!(word.to_bits(), 0x4500_0054);
+
+    assert_eq!(word.version(), u4::new(4));
+    assert_eq!(word.ihl(), u4::new(5));
+    assert_eq!(word.tos(), 0);
+    assert_eq!(word.total_length(), 84);
+
+    let word = word.with_total_length(1500);
+    assert_eq!(word.to_be_bytes(), [0x45, 0x00, 0x05, 0xDC]);
+    assert_eq!(word.to_be_bits().to_ne_bytes(), [0x45, 0x00, 0x05, 0xDC]);
+
+    // every other conversion works on the logical value, in native order
+    let reset = Ipv4Word::default();
+    assert_eq!((reset.version(), reset.ihl()), (u4::new(4), u4::new(5)));
+    assert_eq!(reset.to_bits(), 0x4500_0000);
+    assert_eq!(reset.to_be_bytes(), [0x45, 0x00, 0x00, 0x00]);
+
+    let (low, high) = word.split();
+    assert_eq!((low, high), (1500, 0x4500));
+    assert_eq!(Ipv4Word::join(low, high), word);
+
+    assert_eq!(word.to_le_bytes(), [0xDC, 0x05, 0x00, 0x45]);
+    assert_eq!(Ipv4Word::from_le_bytes(word.to_le_bytes()), word);
+
+    println!("{word:?}");
+}
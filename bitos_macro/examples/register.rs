@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use bitos::{
+    integer::{UnsignedInt, u4, u12, u24},
+    prelude::*,
+};
+
+const MODE_WIDTH: usize = 4;
+
+#[bitos(16)]
+#[derive(Debug, Default)]
+pub struct Control {
+    #[bits(0..MODE_WIDTH)]
+    mode: u4,
+    #[bits(MODE_WIDTH..12, reserved)]
+    _reserved: u8,
+    #[bits(12..16, default = 0b0101)]
+    prescaler: u4,
+}
+
+/// A shadow copy of [`Control`], written by software before being committed.
+#[bitos(16, transmute_from(Control))]
+#[derive(Debug)]
+pub struct ShadowControl {
+    #[bits(0..MODE_WIDTH)]
+    mode: u4,
+    #[bits(12..16)]
+    prescaler: u4,
+}
+
+#[bitos(24)]
+#[derive(Debug)]
+pub struct Address(#[bits(0..24)] pub u24);
+
+#[bitos(64)]
+#[derive(Debug)]
+pub struct Counter {
+    #[bits(0..64)]
+    value: u64,
+}
+
+const CONTROL: Control = Control::from_bits(0)
+    .with_mode_const(u4::const_new(0b1010))
+    .with_prescaler_const(u4::const_new(0b0101));
+
+const _: () = {
+    assert!(bitos::bit_at(1, 0));
+    assert!(bitos::bit_at(1 << 31, 31));
+    assert!(bitos::bit_at(1 << 63, 63));
+    assert!(!bitos::bit_at(u64::MAX >> 1, 63));
+};
+
+/// Formats the raw bits of any register as a binary string of its full width.
+fn dump<R: BitosStruct>(reg: &R) -> String {
+    format!(
+        "{:0width$b}",
+        reg.to_bits().value(),
+        width = <R as BitWidth>::BITS
+    )
+}
+
+fn main() {
+    let control = Control::from_bits(0)
+        .with_mode(u4::new(0b1010))
+        .with_prescaler(u4::new(0b0101));
+
+    assert_eq!(control.to_bits(), 0x500A);
+    assert_eq!(CONTROL.to_bits(), 0x500A);
+    assert_eq!(Control::default().to_bits(), 0x5000);
+    println!("{control:?}");
+
+    let dump = control.fields().collect::<HashMap<_, _>>();
+    assert_eq!(dump["mode"], 0b1010);
+    assert_eq!(dump["prescaler"], 0b0101);
+
+    let changed = control
+        .diff(&Control::from_bits(0x3000))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changed,
+        [("mode", 0b1010, 0), ("prescaler", 0b0101, 0b0011)]
+    );
+
+    let shadow = ShadowControl::from(control);
+    assert_eq!(shadow.mode(), u4::new(0b1010));
+    assert_eq!(shadow.prescaler(), u4::new(0b0101));
+
+    assert_eq!(Counter::VALUE_MASK, u64::MAX);
+    assert_eq!(Counter::from_bits(u64::MAX).value(), u64::MAX);
+
+    let err = u12::try_from(0x1000u64).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "value does not fit in the target integer width 12"
+    );
+    let _: Box<dyn std::error::Error> = Box::new(err);
+    assert_eq!(
+        Address::try_from(1u64 << 24).unwrap_err(),
+        bitos::BitosError::ValueDoesNotFit { width: 24 }
+    );
+
+    let limit = u12::new(0xABC);
+    assert!(limit == 0xABCu64 && limit == 0xABCu16);
+    assert!(limit < 0x1ABCu64 && limit != 0x1ABCu64);
+
+    let address = Address::from_bits(u24::new(0)).with(u24::new(0xAB_CDEF));
+    assert_eq!(address.get(), u24::new(0xAB_CDEF));
+    assert_eq!(dump(&address), "101010111100110111101111");
+    assert_eq!(dump(&CONTROL), "0101000000001010");
+    println!("{address:?}");
+}
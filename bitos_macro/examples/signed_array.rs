@@ -0,0 +1,62 @@
+use bitos::{
+    integer::{i4, u3, u4, u7, u15},
+    prelude::*,
+};
+
+#[bitos(32)]
+#[derive(Debug)]
+pub struct Deltas {
+    #[bits(0..32)]
+    values: [i4; 8],
+}
+
+#[bitos(16)]
+#[derive(Debug)]
+pub struct Nibbles {
+    #[bits(0..16, stride = 4, count = 4)]
+    values: [u4; 4],
+}
+
+#[bitos(15)]
+#[derive(Debug)]
+pub struct Channels {
+    #[bits(0..15)]
+    modes: [u3; 5],
+}
+
+#[bitos(8)]
+#[derive(Debug)]
+pub struct Offset {
+    #[bits(0..7)]
+    magnitude: u7,
+    #[bits(7)]
+    negative: bool,
+}
+
+fn main() {
+    let values = [-8, -3, -1, 0, 1, 3, 5, 7].map(i4::new);
+    let deltas = Deltas::from_bits(0).with_values(values);
+
+    assert_eq!(deltas.values(), values);
+    assert_eq!(deltas.values_at(1), Some(i4::new(-3)));
+    assert_eq!(deltas.values_at(8), None);
+
+    println!("{deltas:?}");
+
+    let nibbles = Nibbles::from_bits(0x4321);
+    let mut buf = [u4::new(0); 2];
+    assert_eq!(nibbles.copy_values_into(&mut buf), 2);
+    assert_eq!(buf, [u4::new(1), u4::new(2)]);
+
+    let nibbles = nibbles.with_values2(u4::new(0xF));
+    assert_eq!(nibbles.values_at(2), Some(nibbles.values2()));
+    assert_eq!(nibbles.values3(), u4::new(4));
+    assert_eq!(nibbles.to_bits(), 0x4F21);
+
+    let channels = Channels::from_bits(u15::new(0)).with_modes_splat(u3::new(0b101));
+    for index in 0..5 {
+        assert_eq!(channels.modes_at(index), Some(u3::new(0b101)));
+    }
+
+    assert_eq!(Offset::from_bits(0xFF).as_signed(), -1);
+}
@@ -1,6 +1,6 @@
 #![warn(clippy::semicolon_if_nothing_returned)]
 
-use bitos::prelude::*;
+use bitos::{integer::u7, prelude::*};
 
 #[bitos(8)]
 #[derive(Debug)]
@@ -24,6 +24,9 @@ where
 // }
 
 fn main() {
+    let masks = Person::<u7>::field_masks().collect::<Vec<_>>();
+    assert_eq!(masks, [("age", 0x7F), ("alive", 0x80)]);
+
     // let person = Person::<i7>::from_bits(0b100_0000);
     // assert_eq!(person.age().value(), 0b1100_0000u8 as i8)
 }
@@ -8,3 +8,14 @@ pub fn bitos(
         Err(e) => e.into_compile_error().into(),
     }
 }
+
+#[proc_macro_attribute]
+pub fn bitflags(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match bitos_macro_core::bitflags_attr(attr.into(), input.into()) {
+        Ok(x) => x.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
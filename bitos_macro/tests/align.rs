@@ -0,0 +1,30 @@
+use bitos::{
+    integer::{u4, u12},
+    prelude::*,
+};
+
+#[bitos(16, align = 8)]
+#[derive(Debug)]
+struct Descriptor {
+    #[bits(0..4)]
+    kind: u4,
+    #[bits(4..16)]
+    length: u12,
+}
+
+#[test]
+fn aligned_wrapper_has_the_requested_alignment() {
+    assert_eq!(core::mem::align_of::<DescriptorAligned>(), 8);
+    assert_eq!(core::mem::size_of::<DescriptorAligned>(), 8);
+    assert_eq!(core::mem::align_of::<Descriptor>(), 2);
+}
+
+#[test]
+fn aligned_wrapper_derefs_to_the_struct() {
+    let mut aligned = DescriptorAligned::from(Descriptor::from_bits(0x0123));
+    assert_eq!(aligned.kind(), u4::new(0x3));
+
+    aligned.set_kind(u4::new(0xA));
+    assert_eq!(aligned.0.to_bits(), 0x012A);
+    assert_eq!(&*aligned as *const Descriptor as usize % 8, 0);
+}
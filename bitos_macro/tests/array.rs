@@ -0,0 +1,31 @@
+use bitos::{integer::u3, prelude::*};
+
+#[bitos(16)]
+#[derive(Debug)]
+struct Triplets {
+    #[bits(2..14)]
+    values: [u3; 4],
+}
+
+#[test]
+fn arrays_of_odd_width_elements_round_trip() {
+    let values = [u3::new(1), u3::new(7), u3::new(0), u3::new(5)];
+    let triplets = Triplets::from_bits(0).with_values(values);
+
+    assert_eq!(triplets.to_bits(), 0x28E4);
+    assert_eq!(triplets.values(), values);
+    for (i, value) in values.into_iter().enumerate() {
+        assert_eq!(triplets.values_at(i), Some(value));
+    }
+}
+
+#[test]
+fn element_setters_of_odd_width_elements_leave_neighbours_untouched() {
+    let mut triplets = Triplets::from_bits(0xFFFF);
+    triplets.set_values_at(2, u3::new(0));
+
+    assert_eq!(triplets.to_bits(), 0xF8FF);
+    assert_eq!(triplets.values_at(1), Some(u3::new(7)));
+    assert_eq!(triplets.values_at(3), Some(u3::new(7)));
+    assert_eq!(triplets.values_at(4), None);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "atomic")]
+
+use bitos::{integer::u4, prelude::*};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[bitos(8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Counter {
+    #[bits(0..4)]
+    count: u4,
+    #[bits(7)]
+    armed: bool,
+}
+
+#[test]
+fn values_are_loaded_and_stored_atomically() {
+    let atomic = AtomicU8::new(0x83);
+    assert_eq!(
+        Counter::load(&atomic, Ordering::Relaxed),
+        Counter::from_bits(0x83)
+    );
+
+    Counter::from_bits(0)
+        .with_count(u4::new(9))
+        .store(&atomic, Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), 0x09);
+}
+
+#[test]
+fn modify_returns_the_previous_value() {
+    let atomic = AtomicU8::new(0x05);
+
+    let previous = Counter::modify(&atomic, Ordering::Relaxed, Ordering::Relaxed, |counter| {
+        counter.with_armed(true)
+    });
+    assert_eq!(previous.to_bits(), 0x05);
+    assert_eq!(atomic.load(Ordering::Relaxed), 0x85);
+}
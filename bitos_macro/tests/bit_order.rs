@@ -0,0 +1,42 @@
+use bitos::{
+    integer::{u4, u12},
+    prelude::*,
+};
+
+#[bitos(16, msb0)]
+#[derive(Debug)]
+struct Mixed {
+    #[bits(0..4)]
+    top: u4,
+    #[bits(0..12, lsb0)]
+    low: u12,
+}
+
+#[bitos(16)]
+#[derive(Debug)]
+struct MixedLsb0 {
+    #[bits(0..4, msb0)]
+    top: u4,
+    #[bits(0..4)]
+    bottom: u4,
+}
+
+#[test]
+fn fields_can_override_an_msb0_struct() {
+    let mixed = Mixed::from_bits(0xA123);
+
+    assert_eq!(mixed.top(), u4::new(0xA));
+    assert_eq!(mixed.low(), u12::new(0x123));
+    assert_eq!(Mixed::TOP_OFFSET, 12);
+    assert_eq!(Mixed::LOW_OFFSET, 0);
+}
+
+#[test]
+fn fields_can_override_an_lsb0_struct() {
+    let mixed = MixedLsb0::from_bits(0)
+        .with_top(u4::new(0x5))
+        .with_bottom(u4::new(0x3));
+
+    assert_eq!(mixed.to_bits(), 0x5003);
+    assert_eq!(MixedLsb0::TOP_MASK, 0xF000);
+}
@@ -0,0 +1,31 @@
+use bitos::{
+    integer::{u3, u6},
+    prelude::*,
+};
+
+#[bitos(6)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mode {
+    #[bits(0..3)]
+    user: u3,
+    #[bits(3..6)]
+    group: u3,
+}
+
+#[test]
+fn bit_strings_round_trip() {
+    let mode = Mode::from_bits(u6::new(0b101_001));
+
+    assert_eq!(mode.to_bit_str(), "101001");
+    assert_eq!(Mode::from_bit_str("101001"), Some(mode));
+    assert_eq!(Mode::from_bit_str(&mode.to_bit_str()), Some(mode));
+    assert_eq!(Mode::from_bit_str("000000").unwrap().group(), u3::new(0));
+}
+
+#[test]
+fn malformed_bit_strings_are_rejected() {
+    assert_eq!(Mode::from_bit_str("10100"), None);
+    assert_eq!(Mode::from_bit_str("1010011"), None);
+    assert_eq!(Mode::from_bit_str("10100x"), None);
+    assert_eq!(Mode::from_bit_str(""), None);
+}
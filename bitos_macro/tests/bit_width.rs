@@ -0,0 +1,29 @@
+use bitos::{
+    BitWidth,
+    integer::{UnsignedInt, u4, u12},
+    prelude::*,
+};
+
+#[bitos(3)]
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn widths_of_bit_types() {
+    assert_eq!(<bool as BitWidth>::BITS, 1);
+    assert_eq!(<u4 as BitWidth>::BITS, 4);
+    assert_eq!(<u12 as BitWidth>::BITS, 12);
+    assert_eq!(<u8 as BitWidth>::BITS, 8);
+    assert_eq!(Color::BITS, 3);
+    assert_eq!(<[u4; 3] as BitWidth>::BITS, 12);
+}
+
+#[test]
+fn integer_widths_agree() {
+    assert_eq!(<u4 as UnsignedInt>::BITS, <u4 as BitWidth>::BITS);
+    assert_eq!(u8::BITS as usize, <u8 as BitWidth>::BITS);
+}
@@ -0,0 +1,44 @@
+use bitos::{
+    integer::{UnsignedInt, u4, u12},
+    prelude::*,
+};
+
+#[bitos(12)]
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    #[bits(0..4)]
+    prescaler: u4,
+    #[bits(4..12)]
+    period: u8,
+}
+
+#[bitos(32)]
+#[derive(Debug, Clone, Copy)]
+struct Status {
+    #[bits(0..32)]
+    flags: u32,
+}
+
+// written once against the trait, for any register
+fn describe(reg: &impl BitosStruct) -> (usize, u64) {
+    fn bits<R: BitosStruct>(_: &R) -> usize {
+        R::BITS
+    }
+
+    (bits(reg), reg.to_bits().value())
+}
+
+#[test]
+fn generic_code_reads_width_and_bits() {
+    let timer = Timer::from_bits(u12::new(0xA53));
+    assert_eq!(describe(&timer), (12, 0xA53));
+
+    let status = Status::from_bits(0xDEAD_BEEF);
+    assert_eq!(describe(&status), (32, 0xDEAD_BEEF));
+}
+
+#[test]
+fn width_matches_bit_width() {
+    assert_eq!(<Timer as BitosStruct>::BITS, <Timer as BitWidth>::BITS);
+    assert_eq!(<Status as BitosStruct>::BITS, <Status as BitWidth>::BITS);
+}
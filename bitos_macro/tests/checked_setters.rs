@@ -0,0 +1,75 @@
+use bitos::{BitosError, integer::u4, prelude::*};
+
+#[bitos(8, checked_setters)]
+#[derive(Debug, PartialEq)]
+struct Level {
+    #[bits(0..4, range = 1..=9)]
+    level: u4,
+    #[bits(4..8)]
+    free: u4,
+}
+
+#[test]
+fn out_of_range_values_are_rejected() {
+    let mut value = Level::from_bits(0x01);
+
+    let err = value.set_level(u4::new(10)).unwrap_err();
+    assert_eq!(
+        err,
+        BitosError::OutOfRange {
+            value: 10,
+            min: 1,
+            max: 9
+        }
+    );
+    assert_eq!(value.level(), u4::new(1));
+
+    let err = value.with_level(u4::new(0)).unwrap_err();
+    assert_eq!(
+        err,
+        BitosError::OutOfRange {
+            value: 0,
+            min: 1,
+            max: 9
+        }
+    );
+}
+
+#[test]
+fn in_range_values_are_written() {
+    let value = Level::from_bits(0x01)
+        .with_level(u4::new(9))
+        .unwrap()
+        .with_free(u4::new(0xF))
+        .unwrap();
+
+    assert_eq!(value.to_bits(), 0xF9);
+}
+
+#[bitos(16, checked_setters)]
+#[derive(Debug)]
+struct Lanes {
+    #[bits(0..16)]
+    lanes: [u4; 4],
+}
+
+#[test]
+fn out_of_bounds_indices_are_rejected() {
+    let mut value = Lanes::from_bits(0);
+
+    let err = value.set_lanes_at(4, u4::new(1)).unwrap_err();
+    assert_eq!(err, BitosError::IndexOutOfBounds { index: 4, len: 4 });
+    assert_eq!(value.to_bits(), 0);
+
+    value.set_lanes_at(3, u4::new(0xA)).unwrap();
+    assert_eq!(value.to_bits(), 0xA000);
+}
+
+#[test]
+fn raw_values_wider_than_the_struct_are_rejected() {
+    assert_eq!(
+        Level::try_from(0x100u64).unwrap_err(),
+        BitosError::ValueDoesNotFit { width: 8 }
+    );
+    assert_eq!(Level::try_from(0x19u64).unwrap().to_bits(), 0x19);
+}
@@ -0,0 +1,28 @@
+use bitos::{
+    integer::{u3, u4},
+    prelude::*,
+};
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Flags {
+    #[bits(0..4, default = 0x5)]
+    mode: u4,
+    #[bits(4..7, reserved)]
+    _reserved: u3,
+    #[bits(7)]
+    enabled: bool,
+}
+
+#[test]
+fn clear_zeroes_every_bit_including_reserved_ones() {
+    let mut flags = Flags::from_bits(0xFF);
+
+    flags.clear();
+    assert_eq!(flags.to_bits(), 0);
+    assert_eq!(flags.mode(), u4::new(0));
+    assert!(!flags.enabled());
+
+    // unlike resetting, clearing ignores field defaults
+    assert_eq!(Flags::new_reset().to_bits(), 0x05);
+}
@@ -0,0 +1,67 @@
+// Checks that accessors of full width fields compile down to plain moves, without any shifting
+// or masking surviving optimization.
+
+#![cfg(target_arch = "x86_64")]
+
+use std::{fs, path::Path, process::Command};
+
+/// Returns the instructions of `symbol` in the assembly listing `asm`.
+fn function_body<'a>(asm: &'a str, symbol: &str) -> Vec<&'a str> {
+    let label = format!("{symbol}:");
+    asm.lines()
+        .skip_while(|line| line.trim() != label)
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with(".Lfunc_end"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('.') && !line.starts_with('#'))
+        .collect()
+}
+
+#[test]
+#[ignore = "builds the codegen example in release mode"]
+fn full_width_accessors_are_plain_moves() {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args([
+            "rustc",
+            "--release",
+            "--example",
+            "full_width_codegen",
+            "--target-dir",
+        ])
+        .arg(&target_dir)
+        .args(["--", "--emit", "asm"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let examples = target_dir.join("release/examples");
+    let asm = fs::read_dir(&examples)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            path.extension().is_some_and(|ext| ext == "s")
+                && path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with("full_width_codegen"))
+        })
+        .map(|path| fs::read_to_string(path).unwrap())
+        .expect("assembly listing should have been emitted");
+
+    for symbol in ["full_width_get", "full_width_set"] {
+        let body = function_body(&asm, symbol);
+        assert!(!body.is_empty(), "`{symbol}` not found in the assembly");
+
+        for instruction in &body {
+            let mnemonic = instruction.split_whitespace().next().unwrap();
+            assert!(
+                !matches!(
+                    mnemonic,
+                    "shl" | "shr" | "sar" | "and" | "or" | "rol" | "ror"
+                ),
+                "`{symbol}` should not shift or mask, found `{instruction}`"
+            );
+        }
+    }
+}
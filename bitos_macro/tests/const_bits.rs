@@ -0,0 +1,41 @@
+use bitos::{integer::u2, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Opcode {
+    Nop = 0x00,
+    Load = 0x12,
+    Store = 0x34,
+}
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Lane {
+    First,
+    Second,
+    Third,
+}
+
+const LOAD: u8 = Opcode::Load.into_bits_const();
+const _: () = assert!(LOAD == 0x12);
+const _: () = assert!(Opcode::Store.into_bits_const() == 0x34);
+
+const THIRD: u2 = Lane::Third.into_bits_const();
+
+const STORE: Option<Opcode> = Opcode::try_from_bits_const(0x34);
+const _: () = assert!(matches!(STORE, Some(Opcode::Store)));
+const _: () = assert!(Opcode::try_from_bits_const(0x13).is_none());
+
+#[test]
+fn into_bits_const_matches_into_bits() {
+    assert_eq!(THIRD, Lane::Third.to_bits());
+    assert_eq!(Opcode::Nop.into_bits_const(), Opcode::Nop.to_bits());
+}
+
+#[test]
+fn try_from_bits_const_matches_try_from_bits() {
+    assert_eq!(STORE, Some(Opcode::Store));
+    assert_eq!(Lane::try_from_bits_const(u2::new(1)), Some(Lane::Second));
+    assert_eq!(Lane::try_from_bits_const(u2::new(3)), None);
+    assert_eq!(Lane::try_from_bits(u2::new(3)), None);
+}
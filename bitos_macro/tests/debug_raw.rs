@@ -0,0 +1,37 @@
+use bitos::{integer::u7, prelude::*};
+
+#[bitos(16, debug_raw)]
+#[derive(Debug)]
+struct Entry {
+    #[bits(0..8)]
+    id: u8,
+    #[bits(8..15, reserved)]
+    _reserved: u7,
+    #[bits(15)]
+    valid: bool,
+}
+
+#[bitos(16)]
+#[derive(Debug)]
+struct PlainEntry {
+    #[bits(0..8)]
+    id: u8,
+    #[bits(15)]
+    valid: bool,
+}
+
+#[test]
+fn debug_raw_prints_the_raw_bits_first() {
+    assert_eq!(
+        format!("{:?}", Entry::from_bits(0x802A)),
+        "Entry { raw: 0x802A, id: 42, _reserved: reserved, valid: true }"
+    );
+}
+
+#[test]
+fn raw_bits_are_omitted_by_default() {
+    assert_eq!(
+        format!("{:?}", PlainEntry::from_bits(0x802A)),
+        "PlainEntry { id: 42, valid: true }"
+    );
+}
@@ -0,0 +1,40 @@
+use bitos::{integer::u4, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug, Default)]
+struct Derived {
+    #[bits(0..4, default = 0xa)]
+    low: u4,
+    #[bits(4..8)]
+    high: u4,
+}
+
+// field defaults must not implement `Default` unless it is derived, so users can write their own
+#[bitos(8)]
+#[derive(Debug)]
+struct Manual {
+    #[bits(0..4, default = 0xa)]
+    low: u4,
+    #[bits(4..8)]
+    high: u4,
+}
+
+impl Default for Manual {
+    fn default() -> Self {
+        Self::new_reset().with_high(u4::new(0x3))
+    }
+}
+
+#[test]
+fn derived_default_uses_field_defaults() {
+    assert_eq!(Derived::default().to_bits(), 0x0a);
+}
+
+#[test]
+fn manual_default_is_not_replaced() {
+    let manual = Manual::default();
+
+    assert_eq!(manual.low(), u4::new(0xa));
+    assert_eq!(manual.high(), u4::new(0x3));
+    assert_eq!(Manual::new_reset().to_bits(), 0x0a);
+}
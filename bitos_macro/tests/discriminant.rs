@@ -0,0 +1,27 @@
+use bitos::{integer::u3, prelude::*};
+
+const BASE: u8 = 2;
+
+#[bitos(3)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    Idle = BASE - 2,
+    Start = BASE + 1,
+    Stop = 1 << BASE,
+    Reset = (BASE * 3) + 1,
+}
+
+#[test]
+fn const_expression_discriminants_are_decoded() {
+    assert_eq!(Command::try_from_bits(u3::new(0)), Some(Command::Idle));
+    assert_eq!(Command::try_from_bits(u3::new(3)), Some(Command::Start));
+    assert_eq!(Command::try_from_bits(u3::new(4)), Some(Command::Stop));
+    assert_eq!(Command::try_from_bits(u3::new(7)), Some(Command::Reset));
+    assert_eq!(Command::try_from_bits(u3::new(1)), None);
+
+    assert_eq!(Command::Stop.to_bits(), u3::new(4));
+    assert_eq!(
+        Command::try_from_bits_const(u3::new(7)),
+        Some(Command::Reset)
+    );
+}
@@ -0,0 +1,31 @@
+use bitos::{
+    integer::{u3, u5},
+    prelude::*,
+};
+
+#[bitos(16, display, parse)]
+#[derive(Debug, PartialEq)]
+struct Entry {
+    #[bits(0..3)]
+    kind: u3,
+    #[bits(3..8)]
+    slot: u5,
+    #[bits(8..16)]
+    length: u8,
+}
+
+#[test]
+fn formats_fields_in_declaration_order() {
+    let entry = Entry::from_bits(0)
+        .with_kind(u3::new(5))
+        .with_slot(u5::new(19))
+        .with_length(42);
+
+    assert_eq!(entry.to_string(), "kind=5,slot=19,length=42");
+}
+
+#[test]
+fn round_trips_through_from_str() {
+    let entry = Entry::from_bits(0xBEEF);
+    assert_eq!(entry.to_string().parse::<Entry>().unwrap(), entry);
+}
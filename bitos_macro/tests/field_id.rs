@@ -0,0 +1,48 @@
+use bitos::{integer::u7, prelude::*};
+
+#[bitos(16, field_id)]
+#[derive(Debug)]
+struct Person {
+    #[bits(0..7)]
+    age: u7,
+    #[bits(7)]
+    employed: bool,
+    #[bits(8..16)]
+    height: u8,
+}
+
+#[test]
+fn identifiers_map_to_fields() {
+    assert_eq!(
+        PersonFieldId::ALL,
+        &[
+            PersonFieldId::Age,
+            PersonFieldId::Employed,
+            PersonFieldId::Height
+        ]
+    );
+    assert_eq!(PersonFieldId::Age.name(), "age");
+    assert_eq!(PersonFieldId::from_name("age"), Some(PersonFieldId::Age));
+    assert_eq!(PersonFieldId::from_name("weight"), None);
+    assert_eq!(PersonFieldId::Height.start(), 8);
+    assert_eq!(PersonFieldId::Age.value_mask(), 0x7F);
+}
+
+#[test]
+fn accessors_by_id_and_name() {
+    let mut person = Person::from_bits(0);
+
+    person.set_by_id(PersonFieldId::Age, 42);
+    assert_eq!(person.age(), u7::new(42));
+    assert_eq!(person.get_by_id(PersonFieldId::Age), 42);
+
+    // values are masked to fit within the field
+    person.set_by_id(PersonFieldId::Age, 0xFF);
+    assert_eq!(person.get_by_id(PersonFieldId::Age), 0x7F);
+    assert!(!person.employed());
+
+    person.set_raw("height", 180).unwrap();
+    assert_eq!(person.get_raw("height"), Some(180));
+    assert_eq!(person.get_raw("weight"), None);
+    assert!(person.set_raw("weight", 1).is_none());
+}
@@ -0,0 +1,50 @@
+use bitos::{integer::u4, prelude::*};
+
+#[bitos(16, from_be)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Port {
+    #[bits(0..16)]
+    number: u16,
+}
+
+#[bitos(32)]
+#[derive(Debug)]
+struct Endpoint {
+    #[bits(0..16)]
+    port: Port,
+    #[bits(16..20)]
+    kind: u4,
+}
+
+#[test]
+fn raw_bits_stay_in_native_order() {
+    let port = Port::from_bits(0x1F90);
+
+    assert_eq!(port.number(), 8080);
+    assert_eq!(port.to_bits(), 0x1F90);
+    assert_eq!(port.inner(), 0x1F90);
+    assert_eq!(u64::from(port), 0x1F90);
+}
+
+#[test]
+fn wire_values_are_converted_explicitly() {
+    let port = Port::from_be_bytes([0x1F, 0x90]);
+
+    assert_eq!(port.number(), 8080);
+    assert_eq!(port.to_be_bytes(), [0x1F, 0x90]);
+    assert_eq!(port.to_be_bits(), 0x1F90u16.to_be());
+    assert_eq!(Port::from_be_bits(port.to_be_bits()), port);
+}
+
+#[test]
+fn nested_big_endian_structs_round_trip() {
+    let port = Port::from_be_bytes([0x01, 0xBB]);
+    let endpoint = Endpoint::from_bits(0)
+        .with_port(port)
+        .with_kind(u4::new(0x6));
+
+    assert_eq!(endpoint.to_bits(), 0x0006_01BB);
+    assert_eq!(endpoint.port(), port);
+    assert_eq!(endpoint.port().number(), 443);
+    assert_eq!(Endpoint::from_bits(endpoint.to_bits()).port(), port);
+}
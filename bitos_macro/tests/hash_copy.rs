@@ -0,0 +1,46 @@
+use bitos::{integer::u4, prelude::*};
+use std::collections::HashMap;
+
+// implements `Bits`, but deliberately none of the derived traits below
+#[bitos(4)]
+struct Nibble {
+    #[bits(0..4)]
+    value: u4,
+}
+
+#[bitos(8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Key<T>
+where
+    T: Bits,
+{
+    #[bits(0..4)]
+    low: T,
+    #[bits(4..8)]
+    high: u4,
+}
+
+#[test]
+fn keys_are_copied_without_bounds_on_fields() {
+    let key = Key::<Nibble>::from_bits(0x12);
+    let copy = key;
+
+    assert_eq!(key.to_bits(), copy.to_bits());
+    assert_eq!(key.low().value(), u4::new(0x2));
+}
+
+#[test]
+fn keys_can_be_hashed_and_ordered() {
+    let mut names = HashMap::new();
+    names.insert(Key::<Nibble>::from_bits(0x12), "a");
+    names.insert(Key::<Nibble>::from_bits(0x34), "b");
+    names.insert(Key::<Nibble>::from_bits(0x12), "c");
+
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[&Key::from_bits(0x12)], "c");
+
+    let mut keys = names.into_keys().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys[0].high(), u4::new(0x1));
+    assert_eq!(keys[1].high(), u4::new(0x3));
+}
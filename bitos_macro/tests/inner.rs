@@ -0,0 +1,30 @@
+use bitos::{
+    integer::{u4, u12},
+    prelude::*,
+};
+
+#[bitos(12)]
+#[derive(Debug)]
+struct Window {
+    #[bits(0..4)]
+    left: u4,
+    #[bits(4..12)]
+    width: u8,
+}
+
+#[test]
+fn inner_returns_the_backing_integer() {
+    let window = Window::from_bits(u12::new(0x2A5));
+
+    assert_eq!(window.inner(), u12::new(0x2A5));
+    assert_eq!(window.inner(), window.to_bits());
+}
+
+#[test]
+fn inner_mut_writes_the_backing_integer_directly() {
+    let mut window = Window::from_bits(u12::new(0));
+
+    *window.inner_mut() = u12::new(0xFF3);
+    assert_eq!(window.left(), u4::new(0x3));
+    assert_eq!(window.width(), 0xFF);
+}
@@ -0,0 +1,44 @@
+use bitos::{integer::u2, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Channel {
+    #[bits(0..2, invalid = 3)]
+    gain: Option<u2>,
+}
+
+#[bitos(8, checked_setters)]
+#[derive(Debug)]
+struct CheckedChannel {
+    #[bits(0..2, invalid = 3)]
+    gain: Option<u2>,
+}
+
+#[test]
+fn none_writes_the_sentinel() {
+    let mut channel = Channel::from_bits(0);
+    channel.set_gain(Some(u2::new(2)));
+    assert_eq!(channel.gain(), Some(u2::new(2)));
+
+    channel.set_gain(None);
+    assert_eq!(channel.to_bits(), 3);
+    assert_eq!(channel.gain(), None);
+}
+
+#[test]
+#[should_panic(expected = "value of field 'gain' collides with its invalid sentinel")]
+fn values_encoding_to_the_sentinel_panic() {
+    Channel::from_bits(0).set_gain(Some(u2::new(3)));
+}
+
+#[test]
+fn values_encoding_to_the_sentinel_are_rejected_when_checked() {
+    let mut channel = CheckedChannel::from_bits(0);
+    assert!(channel.set_gain(Some(u2::new(2))).is_ok());
+
+    assert_eq!(
+        channel.set_gain(Some(u2::new(3))).unwrap_err(),
+        BitosError::InvalidBitPattern
+    );
+    assert_eq!(channel.gain(), Some(u2::new(2)));
+}
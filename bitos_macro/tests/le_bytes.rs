@@ -0,0 +1,38 @@
+use bitos::{
+    integer::{u12, u24},
+    prelude::*,
+};
+
+#[bitos(24)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    #[bits(0..12)]
+    left: u12,
+    #[bits(12..24)]
+    right: u12,
+}
+
+#[bitos(64)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Wide {
+    #[bits(0..64)]
+    value: u64,
+}
+
+#[test]
+fn bytes_of_non_power_of_two_widths_round_trip() {
+    let sample = Sample::from_bits(u24::new(0xABC123));
+
+    assert_eq!(sample.to_le_bytes(), [0x23, 0xC1, 0xAB]);
+    assert_eq!(Sample::from_le_bytes([0x23, 0xC1, 0xAB]), sample);
+    assert_eq!(sample.left(), u12::new(0x123));
+    assert_eq!(sample.right(), u12::new(0xABC));
+}
+
+#[test]
+fn bytes_of_full_width_structs_round_trip() {
+    let wide = Wide::from_bits(0x0123_4567_89AB_CDEF);
+
+    assert_eq!(wide.to_le_bytes(), 0x0123_4567_89AB_CDEFu64.to_le_bytes());
+    assert_eq!(Wide::from_le_bytes(wide.to_le_bytes()), wide);
+}
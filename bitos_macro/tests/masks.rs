@@ -0,0 +1,49 @@
+use bitos::{
+    integer::{u4, u6},
+    prelude::*,
+};
+
+#[bitos(16)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Status {
+    #[bits(0..4)]
+    code: u4,
+    #[bits(4..10, reserved)]
+    _reserved: u6,
+    #[bits(10)]
+    ready: bool,
+    #[bits(12..16)]
+    channel: u4,
+}
+
+#[test]
+fn projecting_keeps_only_the_selected_fields() {
+    let status = Status::from_bits(0xFFFF);
+
+    assert_eq!(status.project(&[Status::CODE_MASK]).to_bits(), 0x000F);
+    assert_eq!(
+        status
+            .project(&[Status::READY_MASK, Status::CHANNEL_MASK])
+            .to_bits(),
+        0xF400
+    );
+    assert_eq!(status.project(&[]).to_bits(), 0);
+    assert_eq!(status.to_bits(), 0xFFFF);
+}
+
+#[test]
+fn offsets_and_widths_describe_each_field() {
+    assert_eq!((Status::CODE_OFFSET, Status::CODE_WIDTH), (0, 4));
+    assert_eq!((Status::READY_OFFSET, Status::READY_WIDTH), (10, 1));
+    assert_eq!((Status::CHANNEL_OFFSET, Status::CHANNEL_WIDTH), (12, 4));
+
+    let mask = ((1u64 << Status::CHANNEL_WIDTH) - 1) << Status::CHANNEL_OFFSET;
+    assert_eq!(mask, Status::CHANNEL_MASK);
+}
+
+#[test]
+fn fields_mask_excludes_reserved_fields_and_gaps() {
+    assert_eq!(Status::FIELDS_MASK, 0xF40F);
+    assert_eq!(Status::reserved_bits(0xFFFF), 0x0BF0);
+    assert_eq!(Status::reserved_bits(0x1405), 0);
+}
@@ -0,0 +1,63 @@
+use bitos::{BitosError, integer::u4, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Counters {
+    #[bits(0..4)]
+    low: u4,
+    #[bits(4..8)]
+    high: u4,
+}
+
+#[bitos(8, checked_setters)]
+#[derive(Debug)]
+struct Bounded {
+    #[bits(0..4, range = 0..=9)]
+    digit: u4,
+    #[bits(4..8)]
+    rest: u4,
+}
+
+#[test]
+fn modify_applies_the_closure() {
+    let mut counters = Counters::from_bits(0x35);
+    counters.modify_low(|low| low + u4::new(2));
+
+    assert_eq!(counters.low(), u4::new(7));
+    assert_eq!(counters.high(), u4::new(3));
+}
+
+#[test]
+fn modify_wraps_around_at_the_field_width() {
+    let mut counters = Counters::from_bits(0x3F);
+    counters.modify_low(|low| low + u4::new(1));
+    assert_eq!(counters.low(), u4::new(0));
+    assert_eq!(counters.high(), u4::new(3));
+
+    counters.modify_low(|low| low - u4::new(1));
+    assert_eq!(counters.low(), u4::new(15));
+
+    counters.modify_high(u4::wrapping_increment);
+    assert_eq!(counters.high(), u4::new(4));
+}
+
+#[test]
+fn modify_reports_errors_of_checked_setters() {
+    let mut bounded = Bounded::from_bits(0x09);
+
+    let err = bounded
+        .modify_digit(|digit| digit + u4::new(1))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BitosError::OutOfRange {
+            value: 10,
+            min: 0,
+            max: 9
+        }
+    );
+    assert_eq!(bounded.digit(), u4::new(9));
+
+    bounded.modify_digit(|digit| digit - u4::new(1)).unwrap();
+    assert_eq!(bounded.digit(), u4::new(8));
+}
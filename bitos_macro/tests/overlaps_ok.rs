@@ -0,0 +1,26 @@
+use bitos::{integer::u4, prelude::*};
+
+// the low byte is either a whole value or a pair of nibbles, depending on the mode
+#[bitos(16)]
+#[derive(Debug)]
+struct Operand {
+    #[bits(0..8)]
+    immediate: u8,
+    #[bits(0..4, overlaps_ok)]
+    lhs: u4,
+    #[bits(4..8, overlaps_ok)]
+    rhs: u4,
+    #[bits(8)]
+    split: bool,
+}
+
+#[test]
+fn overlapping_fields_alias_the_same_bits() {
+    let mut operand = Operand::from_bits(0x0000).with_immediate(0xA5);
+    assert_eq!(operand.lhs(), u4::new(0x5));
+    assert_eq!(operand.rhs(), u4::new(0xA));
+
+    operand.set_split(true).set_rhs(u4::new(0x3));
+    assert_eq!(operand.immediate(), 0x35);
+    assert_eq!(operand.to_bits(), 0x0135);
+}
@@ -0,0 +1,67 @@
+use bitos::{
+    ParseFieldsError,
+    integer::{u3, u5},
+    prelude::*,
+};
+
+#[bitos(16, parse)]
+#[derive(Debug, PartialEq)]
+struct Entry {
+    #[bits(0..3)]
+    kind: u3,
+    #[bits(3..8)]
+    slot: u5,
+    #[bits(8..16)]
+    length: u8,
+}
+
+#[test]
+fn parses_listed_fields() {
+    let entry = "kind=5, slot=0b10011, length=0x2A"
+        .parse::<Entry>()
+        .unwrap();
+
+    assert_eq!(entry.kind(), u3::new(5));
+    assert_eq!(entry.slot(), u5::new(0b10011));
+    assert_eq!(entry.length(), 0x2A);
+}
+
+#[test]
+fn round_trips_through_the_field_values() {
+    let entry = Entry::from_bits(0xBEEF);
+    let text = format!(
+        "kind={},slot={},length={}",
+        entry.kind().value(),
+        entry.slot().value(),
+        entry.length()
+    );
+
+    assert_eq!(text.parse::<Entry>().unwrap(), entry);
+}
+
+#[test]
+fn unlisted_fields_are_zero() {
+    let entry = "slot=3".parse::<Entry>().unwrap();
+    assert_eq!(entry.to_bits(), 3 << 3);
+}
+
+#[test]
+fn unknown_fields_are_rejected() {
+    assert_eq!(
+        "kind=1,flavor=2".parse::<Entry>(),
+        Err(ParseFieldsError::UnknownField)
+    );
+}
+
+#[test]
+fn bad_values_are_rejected() {
+    assert_eq!(
+        "kind=seven".parse::<Entry>(),
+        Err(ParseFieldsError::InvalidValue)
+    );
+    assert_eq!(
+        "kind=8".parse::<Entry>(),
+        Err(ParseFieldsError::ValueDoesNotFit)
+    );
+    assert_eq!("kind".parse::<Entry>(), Err(ParseFieldsError::Malformed));
+}
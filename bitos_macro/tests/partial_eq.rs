@@ -0,0 +1,37 @@
+use bitos::{integer::u7, prelude::*};
+
+// implements `Bits`, but deliberately not `PartialEq`
+#[bitos(7)]
+#[derive(Debug)]
+struct Years {
+    #[bits(0..7)]
+    value: u7,
+}
+
+#[bitos(8)]
+#[derive(Debug, PartialEq, Eq)]
+struct Record<T>
+where
+    T: Bits,
+{
+    #[bits(0..7)]
+    age: T,
+}
+
+#[test]
+fn equality_compares_raw_bits_without_bounds_on_fields() {
+    let a = Record::<Years>::from_bits(0x15);
+    let b = Record::<Years>::from_bits(0x15);
+
+    assert_eq!(a, b);
+    assert_eq!(a.age().value(), u7::new(0x15));
+}
+
+#[test]
+fn equality_includes_bits_outside_of_fields() {
+    let a = Record::<Years>::from_bits(0x15);
+    let b = Record::<Years>::from_bits(0x95);
+
+    assert_ne!(a, b);
+    assert_eq!(a.age().value(), b.age().value());
+}
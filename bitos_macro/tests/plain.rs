@@ -0,0 +1,97 @@
+use bitos::{
+    BitosError,
+    integer::{u3, u4, u6},
+    prelude::*,
+};
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Off,
+    On,
+    Auto,
+}
+
+#[bitos(16, plain)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Config {
+    #[bits(0..4)]
+    level: u4,
+    #[bits(4..6)]
+    mode: Option<Mode>,
+    #[bits(6)]
+    enabled: bool,
+    #[bits(7..10, default = 5)]
+    retries: u3,
+    #[bits(10..16, reserved)]
+    _reserved: u6,
+}
+
+#[bitos(8, plain, checked_setters)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Limits {
+    #[bits(0..4, range = 0..=9)]
+    digit: u4,
+    #[bits(4..8)]
+    rest: u4,
+}
+
+#[test]
+fn round_trips_through_the_plain_struct() {
+    let config = Config::from_bits(0)
+        .with_level(u4::new(9))
+        .with_mode(Mode::Auto)
+        .with_enabled(true)
+        .with_retries(u3::new(2));
+
+    let data = ConfigData::from(config);
+    assert_eq!(
+        data,
+        ConfigData {
+            level: u4::new(9),
+            mode: Some(Mode::Auto),
+            enabled: true,
+            retries: u3::new(2),
+        }
+    );
+
+    // the plain struct gets the same derives, so `data` is `Copy`
+    assert_eq!(Config::from(data), config);
+    assert_eq!(ConfigData::from(Config::from(data)), data);
+}
+
+#[test]
+fn none_fields_keep_their_reset_value() {
+    let data = ConfigData {
+        level: u4::new(1),
+        mode: None,
+        enabled: false,
+        retries: u3::new(5),
+    };
+
+    let config = Config::from(data);
+    assert_eq!(config.mode(), Some(Mode::Off));
+    assert_eq!(config.retries(), u3::new(5));
+}
+
+#[test]
+fn checked_conversions_reject_out_of_range_fields() {
+    let data = LimitsData {
+        digit: u4::new(12),
+        rest: u4::new(1),
+    };
+    assert_eq!(
+        Limits::try_from(data),
+        Err(BitosError::OutOfRange {
+            value: 12,
+            min: 0,
+            max: 9
+        })
+    );
+
+    let data = LimitsData {
+        digit: u4::new(7),
+        rest: u4::new(1),
+    };
+    assert_eq!(Limits::try_from(data).unwrap().to_bits(), 0x17);
+}
@@ -0,0 +1,46 @@
+use bitos::{integer::u3, prelude::*};
+
+#[bitos(3)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Speed {
+    Slow,
+    Medium,
+    Fast,
+}
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Fan {
+    #[bits(0..4, with_presence)]
+    speed: Option<Speed>,
+    #[bits(4..8, with_presence)]
+    level: Option<u3>,
+}
+
+#[test]
+fn presence_bit_marks_the_value_as_set() {
+    let mut fan = Fan::from_bits(0);
+    assert_eq!(fan.speed(), None);
+    assert_eq!(fan.level(), None);
+
+    fan.set_speed(Some(Speed::Slow));
+    fan.set_level(Some(u3::new(5)));
+    assert_eq!(fan.to_bits(), 0xD8);
+    assert_eq!(fan.speed(), Some(Speed::Slow));
+    assert_eq!(fan.level(), Some(u3::new(5)));
+
+    fan.set_speed(None);
+    assert_eq!(fan.to_bits(), 0xD0);
+    assert_eq!(fan.speed(), None);
+}
+
+#[test]
+fn values_without_their_presence_bit_are_absent() {
+    // the value bits hold `Fast`, but the presence bit is clear
+    let fan = Fan::from_bits(0x02);
+    assert_eq!(fan.speed(), None);
+
+    // the presence bit is set, but the value bits hold no variant
+    let fan = Fan::from_bits(0x0F);
+    assert_eq!(fan.speed(), None);
+}
@@ -0,0 +1,58 @@
+use bitos::{BitosError, integer::u4, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Volume {
+    #[bits(0..4, range = 2..12)]
+    level: u4,
+    #[bits(4..8)]
+    balance: u4,
+}
+
+#[test]
+fn try_set_rejects_values_outside_of_the_range() {
+    let mut volume = Volume::from_bits(0x05);
+
+    let err = volume.try_set_level(u4::new(12)).unwrap_err();
+    assert_eq!(
+        err,
+        BitosError::OutOfRange {
+            value: 12,
+            min: 2,
+            max: 11
+        }
+    );
+    assert_eq!(volume.level(), u4::new(5));
+
+    volume.try_set_level(u4::new(11)).unwrap();
+    assert_eq!(volume.level(), u4::new(11));
+}
+
+#[test]
+fn clamp_moves_raw_values_into_the_range() {
+    let mut volume = Volume::from_bits(0x3F);
+    volume.clamp_level();
+    assert_eq!(volume.level(), u4::new(11));
+    assert_eq!(volume.balance(), u4::new(3));
+
+    let mut volume = Volume::from_bits(0x30);
+    volume.clamp_level();
+    assert_eq!(volume.level(), u4::new(2));
+
+    let mut volume = Volume::from_bits(0x37);
+    volume.clamp_level();
+    assert_eq!(volume.level(), u4::new(7));
+}
+
+const LOUD: Volume = Volume::from_bits(0x05).with_level_const(u4::const_new(11));
+
+#[test]
+fn const_setters_accept_values_within_the_range() {
+    assert_eq!(LOUD.level(), u4::new(11));
+}
+
+#[test]
+#[should_panic(expected = "value of field 'level' is outside of its declared range")]
+fn const_setters_reject_values_outside_of_the_range() {
+    Volume::from_bits(0x05).with_level_const(std::hint::black_box(u4::new(12)));
+}
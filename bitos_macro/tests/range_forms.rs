@@ -0,0 +1,31 @@
+use bitos::{
+    integer::{u3, u4},
+    prelude::*,
+};
+
+#[bitos(16)]
+#[derive(Debug)]
+struct Forms {
+    #[bits(..4)]
+    head: u4,
+    #[bits(4..=6)]
+    middle: u3,
+    #[bits(7)]
+    flag: bool,
+    #[bits(12..)]
+    tail: u4,
+}
+
+#[test]
+fn every_range_form_selects_the_expected_bits() {
+    assert_eq!(Forms::HEAD_MASK, 0x000F);
+    assert_eq!(Forms::MIDDLE_MASK, 0x0070);
+    assert_eq!(Forms::FLAG_MASK, 0x0080);
+    assert_eq!(Forms::TAIL_MASK, 0xF000);
+
+    let forms = Forms::from_bits(0xA0D3);
+    assert_eq!(forms.head(), u4::new(0x3));
+    assert_eq!(forms.middle(), u3::new(0b101));
+    assert!(forms.flag());
+    assert_eq!(forms.tail(), u4::new(0xA));
+}
@@ -0,0 +1,30 @@
+use bitos::{
+    integer::{u4, u12},
+    prelude::*,
+};
+use zerocopy::IntoBytes;
+
+#[bitos(16)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Header {
+    #[bits(0..4)]
+    kind: u4,
+    #[bits(4..16)]
+    length: u12,
+}
+
+#[test]
+fn raw_bytes_are_native_endian() {
+    let header = Header::from_bits(0x1234);
+
+    assert_eq!(header.raw_bytes(), 0x1234u16.to_ne_bytes());
+    assert_eq!(header.kind(), u4::new(0x4));
+}
+
+#[test]
+fn raw_bytes_does_not_shadow_into_bytes() {
+    let header = Header::from_bits(0xBEEF);
+
+    assert_eq!(IntoBytes::as_bytes(&header), header.raw_bytes());
+    assert_eq!(header.as_bytes(), 0xBEEFu16.to_ne_bytes());
+}
@@ -0,0 +1,24 @@
+use bitos::{integer::u4, prelude::*};
+
+// deliberately neither `Clone` nor `Copy`: replacing must not need either
+#[bitos(8)]
+#[derive(Debug)]
+struct Pair {
+    #[bits(0..4)]
+    low: u4,
+    #[bits(4..8)]
+    high: u4,
+}
+
+#[test]
+fn replace_leaves_the_original_untouched() {
+    let pair = Pair::from_bits(0x21);
+
+    let replaced = pair.replace_high(u4::new(0xF));
+    assert_eq!(replaced.to_bits(), 0xF1);
+    assert_eq!(pair.to_bits(), 0x21);
+
+    let replaced = pair.replace_low(u4::new(0x0));
+    assert_eq!(replaced.to_bits(), 0x20);
+    assert_eq!(pair.high(), u4::new(0x2));
+}
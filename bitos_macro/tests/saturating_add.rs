@@ -0,0 +1,51 @@
+use bitos::{integer::i4, prelude::*};
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Offsets {
+    #[bits(0..4)]
+    coarse: i4,
+    #[bits(4..8)]
+    fine: i4,
+}
+
+#[bitos(8, checked_setters)]
+#[derive(Debug)]
+struct Gain {
+    #[bits(0..4, range = 0..8)]
+    level: i4,
+}
+
+#[test]
+fn adding_saturates_at_the_bounds_of_the_field() {
+    let mut offsets = Offsets::from_bits(0);
+
+    offsets.saturating_add_to_coarse(i4::new(5));
+    offsets.saturating_add_to_coarse(i4::new(6));
+    assert_eq!(offsets.coarse(), i4::new(7));
+
+    offsets
+        .saturating_add_to_fine(i4::new(-8))
+        .saturating_add_to_fine(i4::new(-1));
+    assert_eq!(offsets.fine(), i4::new(-8));
+    assert_eq!(offsets.coarse(), i4::new(7));
+}
+
+#[test]
+fn checked_adding_propagates_setter_errors() {
+    let mut gain = Gain::from_bits(0x02);
+
+    gain.saturating_add_to_level(i4::new(7)).unwrap();
+    assert_eq!(gain.level(), i4::new(7));
+
+    let err = gain.saturating_add_to_level(i4::new(-8)).unwrap_err();
+    assert_eq!(
+        err,
+        BitosError::OutOfRange {
+            value: 0xF,
+            min: 0,
+            max: 7
+        }
+    );
+    assert_eq!(gain.level(), i4::new(7));
+}
@@ -0,0 +1,49 @@
+use bitos::prelude::*;
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Off,
+    Idle,
+    Busy,
+}
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Level {
+    Low,
+    High,
+    #[fallback]
+    Unknown,
+}
+
+#[test]
+fn variants_round_trip_by_name() {
+    for mode in [Mode::Off, Mode::Idle, Mode::Busy] {
+        let json = serde_json::to_string(&mode).unwrap();
+        assert_eq!(serde_json::from_str::<Mode>(&json).unwrap(), mode);
+    }
+
+    assert_eq!(serde_json::to_string(&Mode::Idle).unwrap(), "\"Idle\"");
+    assert_eq!(
+        serde_json::from_str::<Mode>("\"Busy\"").unwrap(),
+        Mode::Busy
+    );
+}
+
+#[test]
+fn unknown_names_are_rejected_without_a_fallback() {
+    assert!(serde_json::from_str::<Mode>("\"Asleep\"").is_err());
+}
+
+#[test]
+fn unknown_names_deserialize_into_the_fallback() {
+    assert_eq!(
+        serde_json::from_str::<Level>("\"Medium\"").unwrap(),
+        Level::Unknown
+    );
+    assert_eq!(
+        serde_json::from_str::<Level>("\"High\"").unwrap(),
+        Level::High
+    );
+}
@@ -0,0 +1,28 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Irqs {
+    #[bits(0)]
+    timer: bool,
+    #[bits(1)]
+    serial: bool,
+    #[bits(2)]
+    vblank: bool,
+    #[bits(3)]
+    joypad: bool,
+}
+
+#[test]
+fn set_operations_combine_raw_bits() {
+    let pending = Irqs::from_bits(0b0111);
+    let enabled = Irqs::from_bits(0b1101);
+
+    assert_eq!(pending.union(enabled).to_bits(), 0b1111);
+    assert_eq!(pending.intersection(enabled).to_bits(), 0b0101);
+    assert_eq!(pending.difference(enabled).to_bits(), 0b0010);
+
+    let handled = pending.intersection(enabled);
+    assert!(handled.timer() && handled.vblank());
+    assert!(!handled.serial() && !handled.joypad());
+}
@@ -0,0 +1,27 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Leds {
+    #[bits(0)]
+    power: bool,
+    #[bits(5)]
+    activity: bool,
+}
+
+#[test]
+fn toggling_flips_only_the_field() {
+    let mut leds = Leds::from_bits(0b1000_0001);
+
+    leds.toggle_activity();
+    assert!(leds.activity());
+    assert_eq!(leds.to_bits(), 0b1010_0001);
+
+    leds.toggle_activity().toggle_power();
+    assert!(!leds.activity());
+    assert!(!leds.power());
+    assert_eq!(leds.to_bits(), 0b1000_0000);
+
+    let leds = leds.toggled_power();
+    assert_eq!(leds.to_bits(), 0b1000_0001);
+}
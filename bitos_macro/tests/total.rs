@@ -0,0 +1,53 @@
+use bitos::prelude::*;
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Level {
+    Low,
+    High,
+    #[fallback]
+    Unknown,
+}
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Levels {
+    #[bits(0..8, total)]
+    levels: [Level; 4],
+}
+
+#[bitos(8)]
+#[derive(Debug)]
+struct MaybeLevels {
+    #[bits(0..8)]
+    levels: [Level; 4],
+}
+
+#[test]
+fn element_getters_of_total_arrays_are_infallible() {
+    let levels = Levels::from_bits(0b11_10_01_00);
+
+    assert_eq!(levels.levels_at(0), Level::Low);
+    assert_eq!(levels.levels_at(1), Level::High);
+    assert_eq!(levels.levels_at(2), Level::Unknown);
+    // 0b11 has no variant, so it decodes into the fallback
+    assert_eq!(levels.levels_at(3), Level::Unknown);
+    assert_eq!(
+        levels.levels(),
+        [Level::Low, Level::High, Level::Unknown, Level::Unknown]
+    );
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn element_getters_of_total_arrays_panic_out_of_bounds() {
+    Levels::from_bits(0).levels_at(4);
+}
+
+#[test]
+fn element_getters_of_other_arrays_are_fallible() {
+    let levels = MaybeLevels::from_bits(0b11_10_01_00);
+
+    assert_eq!(levels.levels_at(3), Some(Level::Unknown));
+    assert_eq!(levels.levels_at(4), None);
+}
@@ -0,0 +1,32 @@
+use bitos::{integer::u4, prelude::*};
+
+#[bitos(12)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timer {
+    #[bits(0..8)]
+    count: u8,
+    #[bits(8..12)]
+    prescaler: u4,
+}
+
+#[test]
+fn raw_values_within_the_width_are_accepted() {
+    let timer = Timer::try_from(0xA42u64).unwrap();
+
+    assert_eq!(timer.count(), 0x42);
+    assert_eq!(timer.prescaler(), u4::new(0xA));
+    assert_eq!(u64::from(timer), 0xA42);
+    assert_eq!(Timer::try_from(0xFFFu64).map(u64::from), Ok(0xFFF));
+}
+
+#[test]
+fn raw_values_wider_than_12_bits_are_rejected() {
+    assert_eq!(
+        Timer::try_from(0x1000u64),
+        Err(BitosError::ValueDoesNotFit { width: 12 })
+    );
+    assert_eq!(
+        Timer::try_from(u64::MAX),
+        Err(BitosError::ValueDoesNotFit { width: 12 })
+    );
+}
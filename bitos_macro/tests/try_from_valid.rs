@@ -0,0 +1,64 @@
+use bitos::{integer::u2, prelude::*};
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Parity {
+    Off,
+    Even,
+    Odd,
+}
+
+#[bitos(8)]
+#[derive(Debug)]
+struct Serial {
+    #[bits(0..2)]
+    parity: Option<Parity>,
+    #[bits(2..4)]
+    stop_bits: u2,
+    #[bits(4..6)]
+    flow: Option<Parity>,
+}
+
+// empty fields are valid, whether marked by a cleared presence bit or by the invalid sentinel
+#[bitos(8)]
+#[derive(Debug)]
+struct Line {
+    #[bits(0..3, with_presence)]
+    parity: Option<Parity>,
+    #[bits(3..5, invalid = 3)]
+    flow: Option<Parity>,
+}
+
+#[test]
+fn only_values_with_valid_fields_are_accepted() {
+    let serial = Serial::try_from_valid(0b10_01_01).unwrap();
+    assert_eq!(serial.parity(), Some(Parity::Even));
+    assert_eq!(serial.flow(), Some(Parity::Odd));
+
+    assert!(Serial::try_from_valid(0b00_00_11).is_none());
+    assert!(Serial::try_from_valid(0b11_00_00).is_none());
+
+    // bits outside of any field don't matter
+    assert!(Serial::try_from_valid(0b1100_0000).is_some());
+}
+
+#[test]
+fn validity_checks_agree_on_empty_fields() {
+    for raw in 0..0x20u8 {
+        let line = Line::from_bits(raw);
+        let valid = line.is_valid();
+
+        assert_eq!(Line::try_from_valid(raw).is_some(), valid);
+        assert_eq!(line.invalid_fields_mask() == 0, valid);
+    }
+
+    // an absent parity and the flow sentinel
+    assert!(Line::try_from_valid(0x1B).is_some());
+    assert_eq!(Line::from_bits(0x1B).invalid_fields_mask(), 0);
+
+    // a present parity holding no variant
+    let line = Line::from_bits(0x07);
+    assert!(!line.is_valid());
+    assert!(Line::try_from_valid(0x07).is_none());
+    assert_eq!(line.invalid_fields_mask(), Line::PARITY_MASK as u8);
+}
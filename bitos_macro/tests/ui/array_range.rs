@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+struct Levels {
+    #[bits(0..8, range = 0..4)]
+    levels: [bitos::integer::u4; 2],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(200)]
+struct Huge {
+    #[bits(0..8)]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use bitos::prelude::*;
+
+#[bitos(2)]
+#[derive(Debug, Clone, Copy)]
+enum Level {
+    Low,
+    High = 4,
+}
+
+const HIGH: bitos::integer::u2 = Level::High.into_bits_const();
+
+fn main() {
+    let _ = HIGH;
+}
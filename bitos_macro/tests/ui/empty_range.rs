@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+struct Empty {
+    #[bits(4..4)]
+    a: bool,
+}
+
+fn main() {}
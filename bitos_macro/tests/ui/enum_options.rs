@@ -0,0 +1,15 @@
+use bitos::prelude::*;
+
+#[bitos(2, exhaustive)]
+enum Level {
+    Low,
+    High,
+}
+
+#[bitflags(8, checked_setters)]
+enum Permission {
+    Read,
+    Write,
+}
+
+fn main() {}
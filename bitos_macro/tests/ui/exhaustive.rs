@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(8, exhaustive)]
+struct Gappy {
+    #[bits(0..4)]
+    a: bitos::integer::u4,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+struct Open {
+    #[bits(..)]
+    a: u8,
+}
+
+fn main() {}
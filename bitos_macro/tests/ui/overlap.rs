@@ -0,0 +1,11 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+struct Overlapping {
+    #[bits(0..6)]
+    a: bitos::integer::u6,
+    #[bits(4..8)]
+    b: bitos::integer::u4,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use bitos::{integer::u4, prelude::*};
+
+#[bitos(8, plain)]
+#[derive(Debug)]
+struct Tagged<T> {
+    #[bits(0..4)]
+    low: u4,
+    #[bits(4..8)]
+    high: u4,
+}
+
+fn main() {}
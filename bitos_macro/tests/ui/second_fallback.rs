@@ -0,0 +1,11 @@
+use bitos::prelude::*;
+
+#[bitos(2)]
+enum Level {
+    Low,
+    High,
+    #[fallback] Unknown,
+    #[fallback] Reserved,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bitos::prelude::*;
+
+#[bitos(8)]
+struct Swapped {
+    #[bits(4..2)]
+    a: bitos::integer::u2,
+}
+
+fn main() {}
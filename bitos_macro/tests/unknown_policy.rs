@@ -0,0 +1,56 @@
+use bitos::{BitosError, UnknownPolicy, integer::u3, prelude::*};
+
+#[bitos(3)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rate {
+    Slow = 1,
+    Medium = 3,
+    Fast = 6,
+}
+
+#[test]
+fn known_values_ignore_the_policy() {
+    for policy in [
+        UnknownPolicy::Strict,
+        UnknownPolicy::Fallback(Rate::Fast),
+        UnknownPolicy::Clamp,
+    ] {
+        assert_eq!(Rate::from_bits_with(u3::new(3), policy), Ok(Rate::Medium));
+    }
+}
+
+#[test]
+fn strict_rejects_unknown_values() {
+    assert_eq!(
+        Rate::from_bits_with(u3::new(4), UnknownPolicy::Strict),
+        Err(BitosError::InvalidBitPattern)
+    );
+    assert_eq!(
+        Rate::from_bits_with(u3::new(6), UnknownPolicy::Strict),
+        Ok(Rate::Fast)
+    );
+}
+
+#[test]
+fn fallback_replaces_unknown_values() {
+    let policy = UnknownPolicy::Fallback(Rate::Medium);
+    assert_eq!(Rate::from_bits_with(u3::new(0), policy), Ok(Rate::Medium));
+    assert_eq!(Rate::from_bits_with(u3::new(7), policy), Ok(Rate::Medium));
+}
+
+#[test]
+fn clamp_picks_the_closest_lower_variant() {
+    assert_eq!(
+        Rate::from_bits_with(u3::new(5), UnknownPolicy::Clamp),
+        Ok(Rate::Medium)
+    );
+    assert_eq!(
+        Rate::from_bits_with(u3::new(7), UnknownPolicy::Clamp),
+        Ok(Rate::Fast)
+    );
+    // there's no lower variant, so the lowest one is picked
+    assert_eq!(
+        Rate::from_bits_with(u3::new(0), UnknownPolicy::Clamp),
+        Ok(Rate::Slow)
+    );
+}
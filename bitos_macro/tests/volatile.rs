@@ -0,0 +1,34 @@
+use bitos::{
+    integer::{u4, u12},
+    prelude::*,
+};
+
+#[bitos(12)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    #[bits(0..4)]
+    channel: u4,
+    #[bits(4..12)]
+    value: u8,
+}
+
+#[test]
+fn volatile_accesses_round_trip_through_the_storage() {
+    let mut storage = 0u16;
+    let sample = Sample::from_bits(u12::new(0xAB3));
+
+    unsafe { sample.write_volatile(&mut storage) };
+    assert_eq!(storage, 0x0AB3);
+
+    let read = unsafe { Sample::read_volatile(&storage) };
+    assert_eq!(read, sample);
+    assert_eq!(read.channel(), u4::new(0x3));
+}
+
+#[test]
+fn volatile_reads_mask_bits_outside_of_the_width() {
+    let storage = 0xFAB3u16;
+
+    let read = unsafe { Sample::read_volatile(&storage) };
+    assert_eq!(read.to_bits(), u12::new(0xAB3));
+}
@@ -1,6 +1,9 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
-use syn::{Attribute, Error, Expr, LitInt, parse::Parse, punctuated::Punctuated, spanned::Spanned};
+use syn::{
+    Attribute, Error, Expr, Ident, LitInt, Token, parse::Parse, parse_quote,
+    punctuated::Punctuated, spanned::Spanned,
+};
 
 pub fn extract_attr(ident: &str, attrs: &mut Vec<Attribute>) -> Option<Attribute> {
     let index = attrs
@@ -37,91 +40,508 @@ pub fn extract_derive(ident: &str, attrs: &mut [Attribute]) -> bool {
 pub struct BitosAttr {
     pub span: Span,
     pub bitlen: usize,
+    /// Whether every bit of a struct must be covered by some field.
+    pub exhaustive: bool,
+    /// Whether setters of a struct should return an error instead of writing values outside of a
+    /// field's declared range.
+    pub checked_setters: bool,
+    /// Whether the generated `Debug` implementation of a struct should include its raw bits.
+    pub debug_raw: bool,
+    /// Alignment, in bytes, of an aligned wrapper to generate for a struct.
+    pub align: Option<usize>,
+    /// Whether bit ranges of a struct's fields are numbered from the most significant bit.
+    pub msb0: bool,
+    /// Whether a plain companion struct, with one regular field per bitstruct field, should be
+    /// generated for a struct. Not supported on generic structs.
+    pub plain: bool,
+    /// Types with the same layout as a struct, from which a bit-reinterpreting `From`
+    /// implementation should be generated.
+    pub transmute_from: Vec<syn::Type>,
+    /// Whether a struct is read from and written to big-endian wire values, in which case
+    /// explicit `from_be_bits`/`to_be_bits` and `from_be_bytes`/`to_be_bytes` conversions are
+    /// generated. Raw bits are kept in native order everywhere else.
+    pub from_be: bool,
+    /// Whether a `FromStr` implementation parsing `field=value` pairs should be generated for a
+    /// struct.
+    pub parse: bool,
+    /// Whether a `Display` implementation formatting `field=value` pairs should be generated for
+    /// a struct.
+    pub display: bool,
+    /// Whether a field identifier enum and accessors taking field identifiers or names should be
+    /// generated for a struct. Implied by `parse` and `display`.
+    pub field_id: bool,
+    /// The options given after the bit width, in the order they appear.
+    pub options: Vec<Ident>,
+}
+
+impl BitosAttr {
+    /// Fails with an error pointing at the first option given, for items which only accept a
+    /// bit width.
+    pub fn reject_options(&self, kind: &str) -> Result<(), Error> {
+        match self.options.first() {
+            Some(option) => Err(Error::new(
+                option.span(),
+                format!("option '{option}' is only supported on structs, not on {kind}"),
+            )),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Parse for BitosAttr {
     fn parse(input: syn::parse::ParseStream) -> Result<Self, Error> {
-        let bitlen = input.parse::<LitInt>()?;
-        let bitlen = bitlen.base10_parse::<usize>()?;
+        let bitlen_lit = input.parse::<LitInt>()?;
+        let bitlen = bitlen_lit.base10_parse::<usize>()?;
+        if !(1..=64).contains(&bitlen) {
+            return Err(Error::new(
+                bitlen_lit.span(),
+                format!("unsupported bit width {bitlen}: should be in 1..=64"),
+            ));
+        }
+
+        let span = input.span();
+
+        let mut exhaustive = false;
+        let mut checked_setters = false;
+        let mut debug_raw = false;
+        let mut align = None;
+        let mut plain = false;
+        let mut msb0 = false;
+        let mut transmute_from = Vec::new();
+        let mut from_be = false;
+        let mut parse = false;
+        let mut display = false;
+        let mut field_id = false;
+        let mut options = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option = input.parse::<Ident>()?;
+            match option.to_string().as_str() {
+                "exhaustive" => exhaustive = true,
+                "checked_setters" => checked_setters = true,
+                "debug_raw" => debug_raw = true,
+                "plain" => plain = true,
+                "msb0" => msb0 = true,
+                "parse" => parse = true,
+                "display" => display = true,
+                "field_id" => field_id = true,
+                "from_be" => {
+                    if !matches!(bitlen, 8 | 16 | 32 | 64) {
+                        return Err(Error::new(
+                            option.span(),
+                            "`from_be` requires a bit width of 8, 16, 32 or 64",
+                        ));
+                    }
+
+                    from_be = true;
+                }
+                "align" => {
+                    input.parse::<Token![=]>()?;
+                    let value = input.parse::<LitInt>()?;
+                    let value_align = value.base10_parse::<usize>()?;
+                    if !value_align.is_power_of_two() {
+                        return Err(Error::new(value.span(), "alignment must be a power of two"));
+                    }
+
+                    align = Some(value_align);
+                }
+                "transmute_from" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let types = content.parse_terminated(syn::Type::parse, Token![,])?;
+                    transmute_from.extend(types);
+                }
+                _ => {
+                    return Err(Error::new(
+                        option.span(),
+                        format!("unknown option '{option}'"),
+                    ));
+                }
+            }
+
+            options.push(option);
+        }
 
         Ok(Self {
-            span: input.span(),
+            span,
             bitlen,
+            exhaustive,
+            checked_setters,
+            debug_raw,
+            align,
+            plain,
+            msb0,
+            transmute_from,
+            from_be,
+            parse,
+            display,
+            field_id: field_id || parse || display,
+            options,
         })
     }
 }
 
+/// A bound of a bit range. Integer literals are known while expanding the macro, while other
+/// const expressions are only evaluated by the generated code.
+#[derive(Clone)]
+pub enum BitIndex {
+    Lit(usize),
+    Expr(Box<Expr>),
+}
+
+impl BitIndex {
+    fn new(expr: Expr) -> Result<Self, Error> {
+        match &expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int_lit),
+                ..
+            }) => Ok(Self::Lit(int_lit.base10_parse()?)),
+            _ => Ok(Self::Expr(Box::new(expr))),
+        }
+    }
+
+    /// The value of this index, if it is known while expanding the macro.
+    pub fn value(&self) -> Option<usize> {
+        match self {
+            Self::Lit(value) => Some(*value),
+            Self::Expr(_) => None,
+        }
+    }
+
+    /// Returns `self + n`.
+    pub fn add(&self, n: usize) -> Self {
+        match self {
+            Self::Lit(value) => Self::Lit(value + n),
+            Self::Expr(expr) => Self::Expr(Box::new(parse_quote! { (#expr) + #n })),
+        }
+    }
+
+    /// Returns `bitlen - self`.
+    pub fn sub_from(&self, bitlen: usize) -> Self {
+        match self {
+            Self::Lit(value) => Self::Lit(bitlen - value),
+            Self::Expr(expr) => Self::Expr(Box::new(parse_quote! { #bitlen - (#expr) })),
+        }
+    }
+
+    /// Returns an [`usize`] expression for this index, evaluable in const contexts.
+    ///
+    /// Unlike [`BitIndex::to_u8`], this never truncates, so it should be used when validating
+    /// user-provided bounds.
+    pub fn to_usize(&self) -> TokenStream {
+        match self {
+            Self::Lit(value) => quote::quote! { #value },
+            Self::Expr(expr) => quote::quote! { ((#expr) as usize) },
+        }
+    }
+
+    /// Returns an [`u8`] expression for this index, evaluable in const contexts.
+    pub fn to_u8(&self) -> TokenStream {
+        match self {
+            Self::Lit(value) => {
+                let value = *value as u8;
+                quote::quote! { #value }
+            }
+            Self::Expr(expr) => quote::quote! { ((#expr) as u8) },
+        }
+    }
+}
+
+impl std::fmt::Display for BitIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lit(value) => write!(f, "{value}"),
+            Self::Expr(expr) => write!(f, "{}", expr.to_token_stream()),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub enum Bitrange {
-    HalfOpen { start: usize, end: Option<usize> },
-    Closed { start: usize, end: Option<usize> },
+    HalfOpen {
+        start: BitIndex,
+        end: Option<BitIndex>,
+    },
+    Closed {
+        start: BitIndex,
+        end: Option<BitIndex>,
+    },
 }
 
 impl Bitrange {
-    pub fn start(&self) -> usize {
+    pub fn start(&self) -> &BitIndex {
         match self {
-            Bitrange::HalfOpen { start, .. } | Bitrange::Closed { start, .. } => *start,
+            Bitrange::HalfOpen { start, .. } | Bitrange::Closed { start, .. } => start,
         }
     }
 
-    pub fn end(&self) -> Option<usize> {
+    pub fn end(&self) -> Option<&BitIndex> {
         match self {
-            Bitrange::HalfOpen { end, .. } | Bitrange::Closed { end, .. } => *end,
+            Bitrange::HalfOpen { end, .. } | Bitrange::Closed { end, .. } => end.as_ref(),
+        }
+    }
+
+    /// Returns this range, numbered from the most significant bit of a `bitlen` bits value,
+    /// translated into the equivalent range numbered from the least significant bit. Ranges
+    /// ending past `bitlen` are returned as is so that they are reported as out of range.
+    pub fn mirrored(&self, bitlen: usize) -> Bitrange {
+        let start = self.start();
+        let end = self.end().cloned().unwrap_or(BitIndex::Lit(bitlen));
+        let out_of_range = start.value().is_some_and(|start| start > bitlen)
+            || end.value().is_some_and(|end| end > bitlen);
+        let inverted = start.value().zip(end.value()).is_some_and(|(s, e)| s > e);
+        if out_of_range || inverted {
+            return self.clone();
+        }
+
+        Bitrange::HalfOpen {
+            start: end.sub_from(bitlen),
+            end: Some(start.sub_from(bitlen)),
         }
     }
 }
 
+/// An option following the bit range in a `#[bits(..)]` attribute, e.g. `total`.
+pub struct BitsOption {
+    pub ident: Ident,
+    pub value: Option<Expr>,
+}
+
+impl Parse for BitsOption {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, Error> {
+        let ident = input.parse::<Ident>()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<Expr>()?)
+        } else {
+            None
+        };
+
+        Ok(Self { ident, value })
+    }
+}
+
+struct BitsArgs {
+    range: Expr,
+    options: Vec<BitsOption>,
+}
+
+impl Parse for BitsArgs {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, Error> {
+        let range = input.parse::<Expr>()?;
+
+        let mut options = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            options.push(input.parse::<BitsOption>()?);
+        }
+
+        Ok(Self { range, options })
+    }
+}
+
 pub struct BitsAttr {
     pub span: Span,
     pub bitrange: Bitrange,
+    /// Whether the element type of an array field is total, i.e. every bit pattern is either
+    /// valid or maps to a `#[fallback]` variant, in which case element getters are infallible.
+    pub total: bool,
+    /// Whether the field is reserved, in which case no accessors are generated for it.
+    pub reserved: bool,
+    /// The default value of the field's raw bits, used as its reset value.
+    pub default: Option<Expr>,
+    /// The range of valid raw values of the field.
+    pub range: Option<syn::ExprRange>,
+    /// Whether the bit range of the field is numbered from the most significant bit, overriding
+    /// the struct's bit order. Set by the `msb0` and `lsb0` options.
+    pub msb0: Option<bool>,
+    /// Whether the highest bit of the field's range explicitly marks the presence of its value.
+    pub with_presence: bool,
+    /// Whether the field is allowed to overlap with other fields, e.g. to alias bits with
+    /// different meanings depending on a mode.
+    pub overlaps_ok: bool,
+    /// A custom codec used to encode and decode the field's value, implementing
+    /// `bitos::FieldCodec`.
+    pub codec: Option<syn::Path>,
+    /// A raw value of an `Option<T>` field which always decodes to `None`. Setting a value which
+    /// encodes to it is rejected.
+    pub invalid: Option<Expr>,
+    /// Whether the field is itself a bitstruct, whose fields can be accessed through dotted
+    /// paths in the by-name API, e.g. `get_raw("header.flags")`.
+    pub nested: bool,
+    /// The distance in bits between the elements of an array field, given together with `count`
+    /// to generate individually named element accessors.
+    pub stride: Option<usize>,
+    /// The number of elements of an array field, given together with `stride` to generate
+    /// individually named element accessors.
+    pub count: Option<usize>,
 }
 
 impl BitsAttr {
     pub fn extract(attrs: &mut Vec<Attribute>) -> Result<Option<Self>, Error> {
-        let expect_lit_int = |e: Box<Expr>| {
-            if let syn::Expr::Lit(lit_expr) = &*e
-                && let syn::Lit::Int(int_lit) = &lit_expr.lit
-            {
-                int_lit.base10_parse()
-            } else {
-                Err(Error::new(e.span(), "must be an integer literal"))
-            }
-        };
-
         let Some(bitos_attr) = extract_attr("bits", attrs) else {
             return Ok(None);
         };
 
-        let bitrange = if let Ok(int_lit) = bitos_attr.parse_args::<LitInt>() {
-            let start = int_lit.base10_parse()?;
-            Bitrange::HalfOpen {
-                start,
-                end: Some(start + 1),
+        let BitsArgs { range, options } = bitos_attr.parse_args::<BitsArgs>()?;
+        let bitrange = match range {
+            syn::Expr::Range(range_expr)
+                if range_expr.start.is_none() && range_expr.end.is_none() =>
+            {
+                return Err(Error::new(
+                    range_expr.span(),
+                    "fully open bit ranges are not supported, specify at least one bound",
+                ));
             }
-        } else {
-            let range_expr = bitos_attr.parse_args::<syn::ExprRange>()?;
-            match range_expr.limits {
-                syn::RangeLimits::HalfOpen(_) => {
-                    let start = range_expr.start.map(expect_lit_int).unwrap_or(Ok(0))?;
-                    let end = range_expr.end.map(expect_lit_int).transpose()?;
+            syn::Expr::Range(range_expr) => {
+                let start = range_expr
+                    .start
+                    .map(|e| BitIndex::new(*e))
+                    .transpose()?
+                    .unwrap_or(BitIndex::Lit(0));
+                let end = range_expr.end.map(|e| BitIndex::new(*e)).transpose()?;
 
-                    Bitrange::HalfOpen { start, end }
+                match range_expr.limits {
+                    syn::RangeLimits::HalfOpen(_) => Bitrange::HalfOpen { start, end },
+                    syn::RangeLimits::Closed(_) => Bitrange::Closed {
+                        start,
+                        end: end.map(|end| end.add(1)),
+                    },
                 }
-                syn::RangeLimits::Closed(_) => {
-                    let start = range_expr.start.map(expect_lit_int).unwrap_or(Ok(0))?;
-                    let end = range_expr
-                        .end
-                        .map(expect_lit_int)
-                        .transpose()?
-                        .map(|x| x + 1);
-
-                    Bitrange::Closed { start, end }
+            }
+            // a single bit, either as an integer literal or a const expression
+            index => {
+                let start = BitIndex::new(index)?;
+                Bitrange::HalfOpen {
+                    end: Some(start.add(1)),
+                    start,
                 }
             }
         };
 
+        let expect_flag = |option: &BitsOption| match &option.value {
+            Some(value) => Err(Error::new(
+                value.span(),
+                format!("`{}` does not take a value", option.ident),
+            )),
+            None => Ok(true),
+        };
+
+        let mut total = false;
+        let mut reserved = false;
+        let mut default = None;
+        let mut range = None;
+        let mut msb0 = None;
+        let mut with_presence = false;
+        let mut overlaps_ok = false;
+        let mut codec = None;
+        let mut invalid = None;
+        let mut nested = false;
+        let mut stride = None;
+        let mut count = None;
+        for option in options {
+            match option.ident.to_string().as_str() {
+                "total" => total = expect_flag(&option)?,
+                "reserved" => reserved = expect_flag(&option)?,
+                "msb0" => msb0 = Some(expect_flag(&option)?),
+                "lsb0" => msb0 = Some(!expect_flag(&option)?),
+                "with_presence" => with_presence = expect_flag(&option)?,
+                "overlaps_ok" => overlaps_ok = expect_flag(&option)?,
+                "nested" => nested = expect_flag(&option)?,
+                "default" => {
+                    let Some(value) = option.value else {
+                        return Err(Error::new(
+                            option.ident.span(),
+                            "`default` requires a value, e.g. `default = 0b1010`",
+                        ));
+                    };
+
+                    default = Some(value);
+                }
+                "range" => {
+                    let Some(Expr::Range(value)) = option.value else {
+                        return Err(Error::new(
+                            option.ident.span(),
+                            "`range` requires a range of values, e.g. `range = 1..10`",
+                        ));
+                    };
+
+                    range = Some(value);
+                }
+                "codec" => {
+                    let Some(Expr::Path(value)) = option.value else {
+                        return Err(Error::new(
+                            option.ident.span(),
+                            "`codec` requires a path to a codec type, e.g. `codec = MyCodec`",
+                        ));
+                    };
+
+                    codec = Some(value.path);
+                }
+                "stride" | "count" => {
+                    let Some(Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(value),
+                        ..
+                    })) = &option.value
+                    else {
+                        return Err(Error::new(
+                            option.ident.span(),
+                            format!(
+                                "`{}` requires an integer literal, e.g. `{} = 4`",
+                                option.ident, option.ident
+                            ),
+                        ));
+                    };
+
+                    let value = value.base10_parse::<usize>()?;
+                    if option.ident == "stride" {
+                        stride = Some(value);
+                    } else {
+                        count = Some(value);
+                    }
+                }
+                "invalid" => {
+                    let Some(value) = option.value else {
+                        return Err(Error::new(
+                            option.ident.span(),
+                            "`invalid` requires a value, e.g. `invalid = 0`",
+                        ));
+                    };
+
+                    invalid = Some(value);
+                }
+                _ => {
+                    return Err(Error::new(
+                        option.ident.span(),
+                        format!("unknown option '{}'", option.ident),
+                    ));
+                }
+            }
+        }
+
         Ok(Some(Self {
             span: bitos_attr.span(),
             bitrange,
+            total,
+            reserved,
+            default,
+            range,
+            msb0,
+            with_presence,
+            overlaps_ok,
+            codec,
+            invalid,
+            nested,
+            stride,
+            count,
         }))
     }
 }
@@ -1,12 +1,14 @@
 use crate::common::BitosAttr;
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, format_ident};
+use quote::{ToTokens, format_ident, quote_spanned};
 use syn::{Error, Expr, Ident, ItemEnum, Type, Variant, parse_quote_spanned, spanned::Spanned};
 
 struct EnumVariant {
-    _span: Span,
+    span: Span,
     ident: Ident,
-    _value: Option<Expr>,
+    value: Option<Expr>,
+    /// Whether this variant is marked with `#[fallback]`.
+    fallback: bool,
 }
 
 impl EnumVariant {
@@ -14,13 +16,24 @@ impl EnumVariant {
         let span = variant.span();
         let ident = variant.ident.clone();
         let value = variant.discriminant.as_ref().map(|(_, e)| e.clone());
+        let fallback = variant.attrs.iter().any(|a| a.path().is_ident("fallback"));
 
         Ok(Self {
-            _span: span,
+            span,
             ident,
-            _value: value,
+            value,
+            fallback,
         })
     }
+
+    /// The discriminant of this variant as an [`u64`] expression, evaluable in const contexts.
+    fn discriminant(&self, enum_ident: &Ident) -> TokenStream {
+        let ident = &self.ident;
+        match &self.value {
+            Some(value) => quote_spanned! { value.span() => (#value) as u64 },
+            None => quote_spanned! { self.span => #enum_ident::#ident as u64 },
+        }
+    }
 }
 
 pub struct BitEnum {
@@ -29,7 +42,9 @@ pub struct BitEnum {
 }
 
 impl BitEnum {
-    pub fn new(bitos_attr: BitosAttr, e: ItemEnum) -> Result<Self, Error> {
+    pub fn new(bitos_attr: BitosAttr, mut e: ItemEnum) -> Result<Self, Error> {
+        bitos_attr.reject_options("enums")?;
+
         let inner_ty_name = format_ident!("u{}", bitos_attr.bitlen);
         let inner_ty: Box<Type> =
             Box::new(parse_quote_spanned! { bitos_attr.span => ::bitos::integer::#inner_ty_name });
@@ -58,6 +73,20 @@ impl BitEnum {
             return Err(e);
         }
 
+        let mut fallbacks = variants.iter().filter(|v| v.fallback);
+        let fallback = fallbacks.next().map(|v| v.ident.clone());
+        if let Some(extra) = fallbacks.next() {
+            return Err(Error::new(
+                extra.span,
+                "only one variant can be marked as the fallback",
+            ));
+        }
+
+        // `#[fallback]` is not a real attribute, so it must not be emitted
+        for variant in &mut e.variants {
+            variant.attrs.retain(|a| !a.path().is_ident("fallback"));
+        }
+
         let variant_idents = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
         let variant_const_idents = variants
             .iter()
@@ -65,6 +94,30 @@ impl BitEnum {
             .collect::<Vec<_>>();
 
         let ident = &e.ident;
+        let variant_discriminants = variants
+            .iter()
+            .map(|v| v.discriminant(ident))
+            .collect::<Vec<_>>();
+
+        // bit widths are at most 64, so the end of the range always fits in an `u128`
+        let variant_range_end = 1u128
+            .checked_shl(bitos_attr.bitlen as u32)
+            .expect("bit width should be at most 64");
+        let assertions = variants.iter().map(|v| {
+            let variant_ident = &v.ident;
+            let msg = format!(
+                "discriminant of variant '{}' is out of range: should be in 0..{}",
+                variant_ident, variant_range_end
+            );
+
+            quote::quote! {
+                assert!(
+                    (Self::#variant_ident as i128) >= 0
+                        && (Self::#variant_ident as i128) < #variant_range_end as i128,
+                    #msg
+                );
+            }
+        });
 
         let mut generics = e.generics.clone();
         if let Some(wc) = &mut generics.where_clause {
@@ -74,26 +127,211 @@ impl BitEnum {
         }
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let bits_impl = (2usize.pow(bitos_attr.bitlen as u32) == e.variants.len()).then(|| {
+        // enums are total if every bit pattern has a variant or if unknown bit patterns map to a
+        // fallback variant
+        let from_bits_body = if 2usize.pow(bitos_attr.bitlen as u32) == e.variants.len() {
+            Some(quote::quote! {
+                unsafe { <Self as ::bitos::TryBits>::try_from_bits(value).unwrap_unchecked() }
+            })
+        } else {
+            fallback.as_ref().map(|fallback| {
+                quote::quote! {
+                    <Self as ::bitos::TryBits>::try_from_bits(value).unwrap_or(Self::#fallback)
+                }
+            })
+        };
+        let bits_impl = from_bits_body.map(|body| {
             quote::quote! {
                 impl #impl_generics ::bitos::Bits for #ident #ty_generics #where_clause {
                     #[inline(always)]
                     fn from_bits(value: Self::Bits) -> Self {
-                        unsafe { <Self as ::bitos::TryBits>::try_from_bits(value).unwrap_unchecked() }
+                        #body
+                    }
+                }
+            }
+        });
+
+        let storage_bitlen = bitos_attr.bitlen.next_power_of_two().max(8);
+        let storage_ty = format_ident!("u{}", storage_bitlen);
+        let into_bits_const_body = if storage_bitlen == bitos_attr.bitlen {
+            quote::quote! { self as #storage_ty }
+        } else {
+            quote::quote! { #inner_ty::const_new(self as #storage_ty) }
+        };
+
+        let raw_value_const = if storage_bitlen == bitos_attr.bitlen {
+            quote::quote! { raw as u64 }
+        } else {
+            quote::quote! { raw.const_value() as u64 }
+        };
+
+        // variants are serialized by name. unknown names deserialize into the fallback variant,
+        // if any
+        let serde_impl = cfg!(feature = "serde").then(|| {
+            let variant_names = variant_idents
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>();
+            let expecting = format!("a variant name of `{ident}`");
+            let variant_indices = (0..variant_idents.len()).collect::<Vec<_>>();
+            let unknown = match &fallback {
+                Some(fallback) => {
+                    let index = variant_idents.iter().position(|v| v == fallback).unwrap();
+                    quote::quote! { Ok(#index) }
+                }
+                None => quote::quote! {
+                    Err(E::unknown_variant(value, &[#(#variant_names),*]))
+                },
+            };
+
+            let mut de_generics = generics.clone();
+            de_generics.params.insert(0, syn::parse_quote!('de));
+            let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::bitos::__serde::Serialize for #ident #ty_generics #where_clause {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::bitos::__serde::Serializer,
+                    {
+                        let name = match self {
+                            #(
+                                Self::#variant_idents => #variant_names,
+                            )*
+                        };
+
+                        serializer.serialize_str(name)
+                    }
+                }
+
+                #[allow(clippy::all)]
+                impl #de_impl_generics ::bitos::__serde::Deserialize<'de> for #ident #ty_generics #where_clause {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                    where
+                        D: ::bitos::__serde::Deserializer<'de>,
+                    {
+                        // the visitor resolves the name into the index of its variant, so that it
+                        // doesn't depend on the generics of the enum
+                        struct Visitor;
+
+                        impl<'de> ::bitos::__serde::de::Visitor<'de> for Visitor {
+                            type Value = usize;
+
+                            fn expecting(
+                                &self,
+                                f: &mut ::core::fmt::Formatter,
+                            ) -> ::core::fmt::Result {
+                                f.write_str(#expecting)
+                            }
+
+                            fn visit_str<E>(self, value: &str) -> ::core::result::Result<Self::Value, E>
+                            where
+                                E: ::bitos::__serde::de::Error,
+                            {
+                                match value {
+                                    #(
+                                        #variant_names => Ok(#variant_indices),
+                                    )*
+                                    _ => #unknown,
+                                }
+                            }
+                        }
+
+                        match deserializer.deserialize_str(Visitor)? {
+                            #(
+                                #variant_indices => Ok(Self::#variant_idents),
+                            )*
+                            _ => unreachable!(),
+                        }
                     }
                 }
             }
         });
 
         let impl_ = quote::quote! {
+            #[allow(dead_code, clippy::all)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                const fn __assertions() {
+                    #(#assertions)*
+                }
+
+                /// Creates a value of this type from it's raw bit representation, handling values
+                /// which do not correspond to any variant according to `policy`. Only
+                /// [`UnknownPolicy::Strict`](::bitos::UnknownPolicy::Strict) returns an error.
+                #[inline(always)]
+                #[allow(non_upper_case_globals)]
+                pub fn from_bits_with(
+                    raw: #inner_ty,
+                    policy: ::bitos::UnknownPolicy<Self>,
+                ) -> ::core::result::Result<Self, ::bitos::BitosError> {
+                    if let Some(value) = <Self as ::bitos::TryBits>::try_from_bits(raw) {
+                        return Ok(value);
+                    }
+
+                    match policy {
+                        ::bitos::UnknownPolicy::Strict => Err(::bitos::BitosError::InvalidBitPattern),
+                        ::bitos::UnknownPolicy::Fallback(fallback) => Ok(fallback),
+                        ::bitos::UnknownPolicy::Clamp => {
+                            #(
+                                const #variant_const_idents: u64 = #variant_discriminants;
+                            )*
+
+                            let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(raw);
+                            let variants = [#((#variant_const_idents, Self::#variant_idents)),*];
+
+                            let lower = variants
+                                .iter()
+                                .filter(|(discriminant, _)| *discriminant <= raw)
+                                .max_by_key(|(discriminant, _)| *discriminant);
+                            let lowest = variants.iter().min_by_key(|(discriminant, _)| *discriminant);
+
+                            Ok(lower
+                                .or(lowest)
+                                .map(|(_, variant)| *variant)
+                                .expect("bit enums have at least one variant"))
+                        }
+                    }
+                }
+
+                /// Turns this value into it's raw bit representation in a const context.
+                #[inline(always)]
+                pub const fn into_bits_const(self) -> #inner_ty {
+                    const { Self::__assertions() };
+                    #into_bits_const_body
+                }
+
+                /// Tries to create a value of this type from it's raw bit representation in a const
+                /// context.
+                #[inline(always)]
+                #[allow(non_upper_case_globals)]
+                pub const fn try_from_bits_const(raw: #inner_ty) -> ::core::option::Option<Self> {
+                    const { Self::__assertions() };
+
+                    #(
+                        const #variant_const_idents: u64 = #variant_discriminants;
+                    )*
+
+                    match #raw_value_const {
+                        #(
+                            #variant_const_idents => Some(Self::#variant_idents),
+                        )*
+                        _ => None,
+                    }
+                }
+            }
+
             impl #impl_generics ::bitos::TryBits for #ident #ty_generics #where_clause {
                 type Bits = #inner_ty;
 
                 #[inline(always)]
                 #[allow(non_upper_case_globals)]
                 fn try_from_bits(value: Self::Bits) -> ::core::option::Option<Self> {
+                    const { Self::__assertions() };
+
                     #(
-                        const #variant_const_idents: u64 = #ident::#variant_idents as u64;
+                        const #variant_const_idents: u64 = #variant_discriminants;
                     )*
 
                     match <Self::Bits as ::bitos::integer::UnsignedInt>::value(value) {
@@ -106,11 +344,13 @@ impl BitEnum {
 
                 #[inline(always)]
                 fn to_bits(&self) -> Self::Bits {
+                    const { Self::__assertions() };
                     <Self::Bits as ::bitos::integer::UnsignedInt>::new(*self as u64)
                 }
             }
 
             #bits_impl
+            #serde_impl
         };
 
         let enum_repr_size = bitos_attr.bitlen.next_power_of_two().max(8);
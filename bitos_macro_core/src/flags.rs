@@ -0,0 +1,177 @@
+use crate::common::BitosAttr;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident};
+use syn::{Error, ItemEnum, Type, parse_quote_spanned, spanned::Spanned};
+
+pub struct BitFlags {
+    pub def: ItemEnum,
+    pub impl_: TokenStream,
+}
+
+impl BitFlags {
+    pub fn new(bitos_attr: BitosAttr, e: ItemEnum) -> Result<Self, Error> {
+        bitos_attr.reject_options("bitflags")?;
+
+        let inner_ty_name = format_ident!("u{}", bitos_attr.bitlen);
+        let inner_ty: Box<Type> =
+            Box::new(parse_quote_spanned! { bitos_attr.span => ::bitos::integer::#inner_ty_name });
+
+        if let Some(variant) = e.variants.iter().find(|v| !v.fields.is_empty()) {
+            return Err(Error::new(
+                variant.span(),
+                "flag variants must not have fields",
+            ));
+        }
+
+        let ident = &e.ident;
+        let vis = &e.vis;
+        let set_ident = format_ident!("{}Flags", ident);
+        let set_doc = format!("A set of [`{ident}`] flags.");
+        let bitlen = bitos_attr.bitlen;
+
+        let variant_idents = e.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+        let variant_names = variant_idents.iter().map(|v| v.to_string());
+        let assertions = variant_idents.iter().map(|variant_ident| {
+            let msg = format!(
+                "bit index of flag '{}' is out of range: should be in 0..{}",
+                variant_ident, bitlen
+            );
+
+            quote::quote! {
+                assert!(
+                    (#ident::#variant_ident as i128) >= 0
+                        && (#ident::#variant_ident as i128) < #bitlen as i128,
+                    #msg
+                );
+            }
+        });
+
+        let impl_ = quote::quote! {
+            #[doc = #set_doc]
+            #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+            #[repr(transparent)]
+            #vis struct #set_ident(#inner_ty);
+
+            #[allow(dead_code, clippy::all)]
+            impl #set_ident {
+                #[doc(hidden)]
+                const fn __assertions() {
+                    #(#assertions)*
+                }
+
+                #[inline(always)]
+                const fn mask(flag: #ident) -> u64 {
+                    const { Self::__assertions() };
+                    1 << (flag as u64)
+                }
+
+                /// Returns a set with no flags.
+                #[inline(always)]
+                pub fn empty() -> Self {
+                    Self(<#inner_ty as ::bitos::integer::UnsignedInt>::new(0))
+                }
+
+                /// Returns a set with every flag.
+                #[inline(always)]
+                pub fn all() -> Self {
+                    Self(<#inner_ty as ::bitos::integer::UnsignedInt>::new(
+                        0 #(| Self::mask(#ident::#variant_idents))*
+                    ))
+                }
+
+                /// Creates a set from its raw bits.
+                #[inline(always)]
+                pub fn from_bits(value: #inner_ty) -> Self {
+                    Self(value)
+                }
+
+                /// Returns the raw bits of this set.
+                #[inline(always)]
+                pub fn to_bits(&self) -> #inner_ty {
+                    self.0
+                }
+
+                /// Returns whether this set contains no flags.
+                #[inline(always)]
+                pub fn is_empty(&self) -> bool {
+                    <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0) == 0
+                }
+
+                /// Returns whether `flag` is in this set.
+                #[inline(always)]
+                pub fn contains(&self, flag: #ident) -> bool {
+                    <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0) & Self::mask(flag) != 0
+                }
+
+                /// Adds `flag` to this set.
+                #[inline(always)]
+                pub fn insert(&mut self, flag: #ident) -> &mut Self {
+                    let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                    self.0 = <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw | Self::mask(flag));
+                    self
+                }
+
+                /// Removes `flag` from this set.
+                #[inline(always)]
+                pub fn remove(&mut self, flag: #ident) -> &mut Self {
+                    let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                    self.0 = <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw & !Self::mask(flag));
+                    self
+                }
+
+                /// Consumes `self` to add `flag` to it and returns the modified `self`.
+                #[inline(always)]
+                pub fn with(mut self, flag: #ident) -> Self {
+                    self.insert(flag);
+                    self
+                }
+
+                /// Returns a set with the flags in either `self` or `other`.
+                #[inline(always)]
+                pub fn union(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+
+                /// Returns a set with only the flags in both `self` and `other`.
+                #[inline(always)]
+                pub fn intersection(self, other: Self) -> Self {
+                    Self(self.0 & other.0)
+                }
+            }
+
+            #[allow(clippy::all)]
+            impl ::core::convert::From<#ident> for #set_ident {
+                #[inline(always)]
+                fn from(flag: #ident) -> Self {
+                    Self::empty().with(flag)
+                }
+            }
+
+            #[allow(clippy::all)]
+            impl ::core::fmt::Debug for #set_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let mut set = f.debug_set();
+                    #(
+                        if self.contains(#ident::#variant_idents) {
+                            set.entry(&::core::format_args!("{}", #variant_names));
+                        }
+                    )*
+                    set.finish()
+                }
+            }
+        };
+
+        Ok(BitFlags { def: e, impl_ })
+    }
+}
+
+impl ToTokens for BitFlags {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { def, impl_ } = self;
+
+        tokens.extend(quote::quote! {
+            #def
+            #impl_
+        });
+    }
+}
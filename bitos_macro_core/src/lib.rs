@@ -1,5 +1,6 @@
 mod common;
 mod enum_;
+mod flags;
 mod struct_;
 
 use common::BitosAttr;
@@ -19,3 +20,16 @@ pub fn bitos_attr(
         _ => Err(Error::new(input.span(), "Unsupported item")),
     }
 }
+
+pub fn bitflags_attr(
+    attr: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, Error> {
+    let input: Item = parse2(input)?;
+    let bity_attr: BitosAttr = parse2(attr)?;
+
+    match input {
+        Item::Enum(e) => flags::BitFlags::new(bity_attr, e).map(ToTokens::into_token_stream),
+        _ => Err(Error::new(input.span(), "Unsupported item")),
+    }
+}
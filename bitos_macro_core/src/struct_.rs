@@ -1,7 +1,7 @@
 use std::ops::Range;
 
-use crate::common::{BitosAttr, BitsAttr, extract_derive};
-use heck::ToShoutySnakeCase;
+use crate::common::{BitIndex, BitosAttr, BitsAttr, extract_derive};
+use heck::{ToShoutySnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote_spanned};
 use syn::{
@@ -68,6 +68,64 @@ impl FieldTy {
             _ => FieldTy::Simple(Box::new(ty.clone())),
         }
     }
+
+    /// Whether this is a plain signed integer field, i.e. a signed primitive or an `iN`/`SInt`
+    /// from `bitos::integer`.
+    fn is_signed(&self) -> bool {
+        let FieldTy::Simple(ty) = self else {
+            return false;
+        };
+
+        let Type::Path(ty_path) = &**ty else {
+            return false;
+        };
+
+        let Some(last) = ty_path.path.segments.last() else {
+            return false;
+        };
+
+        let name = last.ident.to_string();
+        name == "SInt"
+            || name
+                .strip_prefix('i')
+                .is_some_and(|bits| !bits.is_empty() && bits.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// The bit width of this field's type if it is a plain `bool` (width 1) or unsigned integer,
+    /// i.e. the field types which can be accessed in const contexts.
+    fn const_width(&self) -> Option<usize> {
+        let FieldTy::Simple(ty) = self else {
+            return None;
+        };
+
+        let Type::Path(ty_path) = &**ty else {
+            return None;
+        };
+
+        let last = ty_path.path.segments.last()?;
+        if !last.arguments.is_none() {
+            return None;
+        }
+
+        if self.is_bool() {
+            return Some(1);
+        }
+
+        last.ident
+            .to_string()
+            .strip_prefix('u')
+            .and_then(|bits| bits.parse::<usize>().ok())
+            .filter(|bits| (1..=64).contains(bits))
+    }
+
+    /// Whether this is a plain `bool` field.
+    fn is_bool(&self) -> bool {
+        let FieldTy::Simple(ty) = self else {
+            return false;
+        };
+
+        matches!(&**ty, Type::Path(ty_path) if ty_path.path.is_ident("bool"))
+    }
 }
 
 impl ToTokens for FieldTy {
@@ -89,16 +147,19 @@ struct StructField {
     ty: FieldTy,
     bits: BitsAttr,
     docs: Vec<Attribute>,
+    /// Whether this is the single positional field of a tuple struct.
+    positional: bool,
 }
 
 impl StructField {
-    fn new(field: &Field) -> Result<Self, Error> {
+    fn new(field: &Field, positional: bool) -> Result<Self, Error> {
         let span = field.span();
         let vis = field.vis.clone();
-        let ident = field
-            .ident
-            .clone()
-            .ok_or(Error::new(span, "structs must have named fields"))?;
+        let ident = match &field.ident {
+            Some(ident) => ident.clone(),
+            None if positional => Ident::new("value", span),
+            None => return Err(Error::new(span, "structs must have named fields")),
+        };
         let ty = FieldTy::new(&field.ty);
 
         let mut attrs = field.attrs.clone();
@@ -106,6 +167,77 @@ impl StructField {
             return Err(Error::new(span, "field must have a #[bits(..)] attribute"));
         };
 
+        if bits.total && !matches!(ty, FieldTy::Array { .. }) {
+            return Err(Error::new(
+                bits.span,
+                "`total` is only supported on array fields",
+            ));
+        }
+
+        if bits.with_presence && !matches!(ty, FieldTy::Try(_)) {
+            return Err(Error::new(
+                bits.span,
+                "`with_presence` is only supported on `Option<T>` fields",
+            ));
+        }
+
+        if bits.invalid.is_some() && (bits.with_presence || !matches!(ty, FieldTy::Try(_))) {
+            return Err(Error::new(
+                bits.span,
+                "`invalid` is only supported on `Option<T>` fields without `with_presence`",
+            ));
+        }
+
+        if bits.nested && (bits.reserved || !matches!(ty, FieldTy::Simple(_))) {
+            return Err(Error::new(
+                bits.span,
+                "`nested` is only supported on plain, non-reserved bitstruct fields",
+            ));
+        }
+
+        if bits.stride.is_some() != bits.count.is_some() {
+            return Err(Error::new(
+                bits.span,
+                "`stride` and `count` must be given together",
+            ));
+        }
+
+        if bits.count.is_some() {
+            let FieldTy::Array { len, .. } = &ty else {
+                return Err(Error::new(
+                    bits.span,
+                    "`stride` and `count` are only supported on array fields",
+                ));
+            };
+
+            if let Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(len),
+                ..
+            }) = len
+                && let Ok(len) = len.base10_parse::<usize>()
+                && Some(len) != bits.count
+            {
+                return Err(Error::new(
+                    bits.span,
+                    format!("`count` does not match the length of the array ({len})"),
+                ));
+            }
+        }
+
+        if bits.range.is_some() && matches!(ty, FieldTy::Array { .. }) {
+            return Err(Error::new(
+                bits.span,
+                "`range` is not supported on array fields",
+            ));
+        }
+
+        if bits.codec.is_some() && !matches!(ty, FieldTy::Simple(_)) {
+            return Err(Error::new(
+                bits.span,
+                "`codec` is only supported on plain (non-array, non-`Option`) fields",
+            ));
+        }
+
         let docs = attrs
             .extract_if(.., |a| a.meta.path().is_ident("doc"))
             .collect();
@@ -117,68 +249,248 @@ impl StructField {
             ty,
             bits,
             docs,
+            positional,
         })
     }
 
-    fn bitrange(&self, bitstruct: &BitStructInput) -> Range<usize> {
-        let bits_start = self.bits.bitrange.start();
-        let bits_end = self
-            .bits
-            .bitrange
-            .end()
-            .unwrap_or(bitstruct.bitos_attr.bitlen);
+    /// Returns the identifier of an accessor of this field, e.g. `set_<field>` for the prefix
+    /// `set_`. Positional fields use bare accessor names instead, e.g. `get` and `set`.
+    fn accessor_ident(&self, prefix: &str, suffix: &str) -> Ident {
+        if !self.positional {
+            return format_ident!("{}{}{}", prefix, self.ident, suffix);
+        }
+
+        let prefix = prefix.trim_end_matches('_');
+        let prefix = if prefix.is_empty() { "get" } else { prefix };
+        format_ident!("{}{}", prefix, suffix)
+    }
+
+    /// The bit range of this field, if its bounds are known while expanding the macro.
+    fn bitrange(&self, bitstruct: &BitStructInput) -> Option<Range<usize>> {
+        let bits_start = self.bits.bitrange.start().value()?;
+        let bits_end = match self.bits.bitrange.end() {
+            Some(end) => end.value()?,
+            None => bitstruct.bitos_attr.bitlen,
+        };
+
+        Some(bits_start..bits_end)
+    }
+
+    /// [`u8`] expressions for the start and end of the bit range of this field.
+    fn bounds(&self, bitstruct: &BitStructInput) -> (TokenStream, TokenStream) {
+        let bits_start = self.bits.bitrange.start().to_u8();
+        let bits_end = match self.bits.bitrange.end() {
+            Some(end) => end.to_u8(),
+            None => BitIndex::Lit(bitstruct.bitos_attr.bitlen).to_u8(),
+        };
 
-        bits_start..bits_end
+        (bits_start, bits_end)
     }
 
     fn bitlen(&self) -> Expr {
+        if let Some(codec) = &self.bits.codec {
+            let ty = &self.ty;
+            return parse_quote_spanned! {
+                codec.span() =>
+                <<#codec as ::bitos::FieldCodec<#ty>>::Bits as ::bitos::integer::UnsignedInt>::BITS
+            };
+        }
+
         match &self.ty {
             FieldTy::Simple(ty) => {
-                parse_quote_spanned! { ty.span() => <<#ty as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::BITS }
+                parse_quote_spanned! { ty.span() => <#ty as ::bitos::BitWidth>::BITS }
             }
             FieldTy::Array { span, elem, len } => {
-                parse_quote_spanned! { *span => <<#elem as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::BITS * #len }
+                parse_quote_spanned! { *span => <#elem as ::bitos::BitWidth>::BITS * #len }
+            }
+            FieldTy::Try(ty) if self.bits.with_presence => {
+                parse_quote_spanned! { ty.span() => <#ty as ::bitos::BitWidth>::BITS + 1 }
             }
             FieldTy::Try(ty) => {
-                parse_quote_spanned! { ty.span() => <<#ty as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::BITS }
+                parse_quote_spanned! { ty.span() => <#ty as ::bitos::BitWidth>::BITS }
             }
         }
     }
 
+    /// Expressions for the smallest and largest raw values of the declared range of this field,
+    /// if any.
+    fn range_bounds(&self, bitstruct: &BitStructInput) -> Option<(TokenStream, TokenStream)> {
+        let range = self.bits.range.as_ref()?;
+        let (bits_start, bits_end) = self.bounds(bitstruct);
+
+        let min = match &range.start {
+            Some(start) => quote::quote! { (#start) as u64 },
+            None => quote::quote! { 0u64 },
+        };
+        let max = match (&range.end, &range.limits) {
+            (Some(end), syn::RangeLimits::HalfOpen(_)) => {
+                quote::quote! { ((#end) as u64).saturating_sub(1) }
+            }
+            (Some(end), syn::RangeLimits::Closed(_)) => quote::quote! { (#end) as u64 },
+            (None, _) => {
+                quote::quote! { ::bitos::integer::mask_to_width(u64::MAX, #bits_end - #bits_start) }
+            }
+        };
+
+        Some((min, max))
+    }
+
     fn assertions(&self, bitstruct: &BitStructInput) -> Expr {
         let field_ty_bitlen = self.bitlen();
-        let range = self.bitrange(bitstruct);
-        let specified_bitlen = range.end.saturating_sub(range.start);
+        let (bits_start, bits_end) = self.bounds(bitstruct);
+        let specified_bitlen = quote::quote! { #bits_end.saturating_sub(#bits_start) };
         let bitlen = bitstruct.bitos_attr.bitlen;
         let bitlen_msg = format!("field '{}' has wrong bit length", self.ident);
 
-        let start_err = (range.start > bitlen).then(|| {
-            Error::new(
-                self.bits.span,
-                format!(
-                    "start of field '{}' is out of range: should be in 0..{}",
-                    self.ident, bitlen
-                ),
-            )
-            .into_compile_error()
+        let start_err = self
+            .bits
+            .bitrange
+            .start()
+            .value()
+            .is_some_and(|start| start > bitlen)
+            .then(|| {
+                Error::new(
+                    self.bits.span,
+                    format!(
+                        "start of field '{}' is out of range: should be in 0..{}",
+                        self.ident, bitlen
+                    ),
+                )
+                .into_compile_error()
+            });
+        let end_err = self
+            .bits
+            .bitrange
+            .end()
+            .and_then(|end| end.value())
+            .is_some_and(|end| end > bitlen)
+            .then(|| {
+                Error::new(
+                    self.bits.span,
+                    format!(
+                        "end of field '{}' is out of range: should be in 0..{}",
+                        self.ident, bitlen
+                    ),
+                )
+                .into_compile_error()
+            });
+
+        // bounds given as const expressions can only be checked by the generated code
+        let range_assertion = self.bitrange(bitstruct).is_none().then(|| {
+            let order_msg = format!(
+                "bit range of field '{}' is empty or has swapped bounds: start must be less than end",
+                self.ident
+            );
+            let range_msg = format!(
+                "bit range of field '{}' is out of range: should be within 0..{}",
+                self.ident, bitlen
+            );
+
+            // compare before narrowing to u8, so that bounds past 255 are not truncated
+            let bits_start = self.bits.bitrange.start().to_usize();
+            let bits_end = match self.bits.bitrange.end() {
+                Some(end) => end.to_usize(),
+                None => BitIndex::Lit(bitstruct.bitos_attr.bitlen).to_usize(),
+            };
+
+            quote::quote! {
+                assert!(#bits_start < #bits_end, #order_msg);
+                assert!(#bits_end <= #bitlen, #range_msg);
+            }
         });
-        let end_err = (range.end > bitlen).then(|| {
-            Error::new(
-                self.bits.span,
-                format!(
-                    "end of field '{}' is out of range: should be in 0..{}",
-                    self.ident, bitlen
-                ),
-            )
-            .into_compile_error()
+
+        let default_assertion = self.bits.default.as_ref().map(|default| {
+            let default_msg = format!(
+                "default value of field '{}' does not fit within the field",
+                self.ident
+            );
+
+            quote_spanned! {
+                default.span() =>
+                assert!(
+                    (#default) as u64
+                        <= ::bitos::integer::mask_to_width(u64::MAX, #specified_bitlen),
+                    #default_msg
+                );
+            }
+        });
+
+        let invalid_assertion = self.bits.invalid.as_ref().map(|invalid| {
+            let invalid_msg = format!(
+                "invalid value of field '{}' does not fit within the field",
+                self.ident
+            );
+
+            quote_spanned! {
+                invalid.span() =>
+                assert!(
+                    (#invalid) as u64
+                        <= ::bitos::integer::mask_to_width(u64::MAX, #specified_bitlen),
+                    #invalid_msg
+                );
+            }
+        });
+
+        let value_range_assertion = self.range_bounds(bitstruct).map(|(min, max)| {
+            let range = self.bits.range.as_ref().unwrap();
+            let order_msg = format!(
+                "value range of field '{}' is empty: start must not be greater than end",
+                self.ident
+            );
+            let fit_msg = format!(
+                "value range of field '{}' does not fit within the field",
+                self.ident
+            );
+            // half-open ranges are empty if their end isn't greater than their start, which
+            // the saturating `max` would hide for an end of zero. the bounds are compared through
+            // `checked_sub` so that literal bounds of zero don't trigger `unused_comparisons`
+            let nonempty = match (&range.end, &range.limits) {
+                (Some(end), syn::RangeLimits::HalfOpen(_)) => {
+                    quote::quote! { matches!(((#end) as u64).checked_sub(#min), Some(1..)) }
+                }
+                _ => quote::quote! { (#max).checked_sub(#min).is_some() },
+            };
+
+            quote_spanned! {
+                range.span() =>
+                assert!(#nonempty, #order_msg);
+                assert!(
+                    #max <= ::bitos::integer::mask_to_width(u64::MAX, #specified_bitlen),
+                    #fit_msg
+                );
+            }
         });
 
+        let stride_assertion = match (&self.ty, self.bits.stride, self.bits.count) {
+            (FieldTy::Array { elem, len, .. }, Some(stride), Some(count)) => {
+                let stride_msg = format!(
+                    "`stride` of field '{}' does not match the bit width of its elements",
+                    self.ident
+                );
+                let count_msg = format!(
+                    "`count` of field '{}' does not match the length of the array",
+                    self.ident
+                );
+
+                Some(quote::quote! {
+                    assert!(<#elem as ::bitos::BitWidth>::BITS == #stride, #stride_msg);
+                    assert!((#len) == #count, #count_msg);
+                })
+            }
+            _ => None,
+        };
+
         parse_quote_spanned! {
             self.bits.span =>
             {
                 #start_err
                 #end_err
-                assert!(#field_ty_bitlen == #specified_bitlen, #bitlen_msg);
+                #range_assertion
+                #stride_assertion
+                #value_range_assertion
+                assert!(#field_ty_bitlen == (#specified_bitlen as usize), #bitlen_msg);
+                #default_assertion
+                #invalid_assertion
             }
         }
     }
@@ -192,14 +504,20 @@ impl StructField {
             ..
         } = self;
 
-        let bits_start = bits.bitrange.start() as u8;
-        let bits_end = bits.bitrange.end().unwrap_or(bitstruct.bitos_attr.bitlen) as u8;
-        let len = bits_end.saturating_sub(bits_start);
-        let mask_value = (((1u128 << len) - 1) as u64) << bits_start;
-        let mask_value = mask_value & (((1u128 << bitstruct.bitos_attr.bitlen) - 1) as u64);
+        let (bits_start, bits_end) = self.bounds(bitstruct);
+        let len = quote::quote! { #bits_end.saturating_sub(#bits_start) };
+        let mask_end = match self.bitrange(bitstruct) {
+            Some(range) => BitIndex::Lit(range.end.min(bitstruct.bitos_attr.bitlen)).to_u8(),
+            None => bits_end.clone(),
+        };
 
-        let mask_ident = format_ident!("{}_MASK", ident.to_string().to_shouty_snake_case());
-        let mask = quote::quote! { #mask_value as _ };
+        let field_ident_shouty = ident.to_string().to_shouty_snake_case();
+        let mask_ident = format_ident!("{}_MASK", field_ident_shouty);
+        let offset_ident = format_ident!("{}_OFFSET", field_ident_shouty);
+        let width_ident = format_ident!("{}_WIDTH", field_ident_shouty);
+        // `range_mask` never shifts by 64 or more, so fields covering all 64 bits of a struct get
+        // a mask of `u64::MAX` instead of overflowing
+        let mask = quote::quote! { ::bitos::integer::range_mask(#bits_start, #mask_end) };
 
         Ok(quote_spanned! {
             *span =>
@@ -207,6 +525,16 @@ impl StructField {
             #[doc = stringify!(#ident)]
             #[doc = "` field are set"]
             #vis const #mask_ident: u64 = #mask;
+
+            #[doc = "Index of the lowest bit of the `"]
+            #[doc = stringify!(#ident)]
+            #[doc = "` field"]
+            #vis const #offset_ident: u8 = #bits_start;
+
+            #[doc = "Length in bits of the `"]
+            #[doc = stringify!(#ident)]
+            #[doc = "` field"]
+            #vis const #width_ident: u8 = #len;
         })
     }
 
@@ -218,14 +546,60 @@ impl StructField {
             ty: field_ty,
             bits,
             docs,
+            ..
         } = self;
 
-        let bits_start = bits.bitrange.start() as u8;
-        let bits_end = bits.bitrange.end().unwrap_or(bitstruct.bitos_attr.bitlen) as u8;
+        let (bits_start, bits_end) = self.bounds(bitstruct);
+
+        if bits.reserved {
+            return Ok(TokenStream::new());
+        }
 
         let inner_ty = &bitstruct.inner_ty;
         let field_ident_str = ident.to_string();
-        let field_getter_ident = format_ident!("{}", ident);
+        let field_getter_ident = self.accessor_ident("", "");
+
+        // individually named element accessors, from `stride` and `count`
+        let named_getters = match (field_ty, bits.count) {
+            (FieldTy::Array { elem, .. }, Some(count)) => {
+                let field_elem_getter_ident = self.accessor_ident("", "_at");
+                // the count is asserted to match the length, so the index is always in bounds
+                let unwrap = (!bits.total).then(|| quote::quote! { .unwrap() });
+                let accessors = (0..count).map(|index| {
+                    let getter_ident = self.accessor_ident("", &index.to_string());
+                    let doc = format!("Gets element {index} of the `{field_ident_str}` field.");
+
+                    quote_spanned! {
+                        *span =>
+                        #[doc = #doc]
+                        #[inline(always)]
+                        #vis fn #getter_ident (&self) -> #elem {
+                            self.#field_elem_getter_ident(#index)#unwrap
+                        }
+                    }
+                });
+
+                quote::quote! { #(#accessors)* }
+            }
+            _ => TokenStream::new(),
+        };
+
+        if let Some(codec) = &bits.codec {
+            return Ok(quote_spanned! {
+                *span =>
+                #(#docs)*
+                #[inline(always)]
+                #vis fn #field_getter_ident (&self) -> #field_ty {
+                    const { Self::__assertions() };
+
+                    let bits = ::bitos::extract_field::<
+                        #inner_ty,
+                        <#codec as ::bitos::FieldCodec<#field_ty>>::Bits,
+                    >(self.0, #bits_start, #bits_end);
+                    <#codec as ::bitos::FieldCodec<#field_ty>>::decode(bits)
+                }
+            });
+        }
 
         match field_ty {
             FieldTy::Simple(field_ty) => Ok(quote_spanned! {
@@ -237,16 +611,79 @@ impl StructField {
                     use bitos::{TryBits, Bits, BitUtils, integer::UnsignedInt};
                     const { Self::__assertions() };
 
-                    let extracted_bits = self.0.bits(#bits_start, #bits_end);
-                    let extracted_downcast = <<#field_ty as TryBits>::Bits as UnsignedInt>::new(
-                        <#inner_ty as UnsignedInt>::value(extracted_bits)
-                    );
-
-                    <#field_ty>::from_bits(extracted_downcast)
+                    ::bitos::extract_field::<#inner_ty, #field_ty>(self.0, #bits_start, #bits_end)
                 }
             }),
+            FieldTy::Array { elem, len, .. } if bits.total => {
+                let field_elem_getter_ident = self.accessor_ident("", "_at");
+                let field_copy_into_ident = self.accessor_ident("copy_", "_into");
+
+                Ok(quote_spanned! {
+                    *span =>
+                    #[doc = "Gets the element at the given index in the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field."]
+                    #[doc = ""]
+                    #[doc = "# Panics"]
+                    #[doc = "Panics if `index` is out of bounds."]
+                    #[inline(always)]
+                    #vis fn #field_elem_getter_ident (&self, index: usize) -> #elem {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, Bits, BitUtils, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        assert!(index < #len, "index out of bounds");
+
+                        let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+                        let extracted_bits = ::bitos::chunks::chunk(
+                            <#inner_ty as UnsignedInt>::value(self.0),
+                            #bits_start,
+                            elem_len,
+                            index,
+                        );
+                        let extracted_downcast = <<#elem as TryBits>::Bits as UnsignedInt>::new(extracted_bits);
+
+                        <#elem>::from_bits(extracted_downcast)
+                    }
+
+                    #[doc = "Copies the elements of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field into `buf`, as many as fit. Returns the number of elements copied."]
+                    #[inline(always)]
+                    #vis fn #field_copy_into_ident (&self, buf: &mut [#elem]) -> usize {
+                        let count = buf.len().min(#len);
+                        for (index, slot) in buf[..count].iter_mut().enumerate() {
+                            *slot = self.#field_elem_getter_ident(index);
+                        }
+
+                        count
+                    }
+
+                    #named_getters
+
+                    #(#docs)*
+                    #[inline(always)]
+                    #vis fn #field_getter_ident (&self) -> #field_ty {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, Bits, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+                        let chunks = ::bitos::chunks::split_into::<{ #len }>(
+                            <#inner_ty as UnsignedInt>::value(self.0),
+                            #bits_start,
+                            elem_len,
+                        );
+
+                        chunks.map(|bits| {
+                            <#elem>::from_bits(<<#elem as TryBits>::Bits as UnsignedInt>::new(bits))
+                        })
+                    }
+                })
+            }
             FieldTy::Array { elem, len, .. } => {
-                let field_elem_getter_ident = format_ident!("{}_at", ident);
+                let field_elem_getter_ident = self.accessor_ident("", "_at");
+                let field_copy_into_ident = self.accessor_ident("copy_", "_into");
 
                 Ok(quote_spanned! {
                     *span =>
@@ -260,27 +697,57 @@ impl StructField {
                         const { Self::__assertions() };
 
                         (index < #len).then(|| {
-                            let elem_len = <#elem as TryBits>::Bits::BITS as u8;
-                            let offset = #bits_start + elem_len * index as u8;
-                            let extracted_bits = self.0.bits(offset, offset + elem_len);
-                            let extracted_downcast = <<#elem as TryBits>::Bits as UnsignedInt>::new(
-                                <#inner_ty as UnsignedInt>::value(extracted_bits)
+                            let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+                            let extracted_bits = ::bitos::chunks::chunk(
+                                <#inner_ty as UnsignedInt>::value(self.0),
+                                #bits_start,
+                                elem_len,
+                                index,
                             );
+                            let extracted_downcast = <<#elem as TryBits>::Bits as UnsignedInt>::new(extracted_bits);
 
                             <#elem>::from_bits(extracted_downcast)
                         })
+                    }
+
+                    #[doc = "Copies the elements of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field into `buf`, as many as fit. Returns the number of elements copied."]
+                    #[inline(always)]
+                    #vis fn #field_copy_into_ident (&self, buf: &mut [#elem]) -> usize {
+                        let count = buf.len().min(#len);
+                        for (index, slot) in buf[..count].iter_mut().enumerate() {
+                            if let Some(elem) = self.#field_elem_getter_ident(index) {
+                                *slot = elem;
+                            }
+                        }
 
+                        count
                     }
 
+                    #named_getters
+
                     #(#docs)*
                     #[inline(always)]
                     #vis fn #field_getter_ident (&self) -> #field_ty {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, Bits, integer::UnsignedInt};
                         const { Self::__assertions() };
-                        core::array::from_fn(|i| unsafe { self.#field_elem_getter_ident(i).unwrap_unchecked() })
+
+                        let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+                        let chunks = ::bitos::chunks::split_into::<{ #len }>(
+                            <#inner_ty as UnsignedInt>::value(self.0),
+                            #bits_start,
+                            elem_len,
+                        );
+
+                        chunks.map(|bits| {
+                            <#elem>::from_bits(<<#elem as TryBits>::Bits as UnsignedInt>::new(bits))
+                        })
                     }
                 })
             }
-            FieldTy::Try(field_ty) => Ok(quote_spanned! {
+            FieldTy::Try(field_ty) if bits.with_presence => Ok(quote_spanned! {
                 *span =>
                 #(#docs)*
                 #[inline(always)]
@@ -289,12 +756,45 @@ impl StructField {
                     use bitos::{TryBits, BitUtils, integer::UnsignedInt};
                     const { Self::__assertions() };
 
-                    let extracted_bits = self.0.bits(#bits_start, #bits_end);
-                    let extracted_downcast = <<#field_ty as TryBits>::Bits as UnsignedInt>::new(
-                        <#inner_ty as UnsignedInt>::value(extracted_bits)
-                    );
+                    if !self.0.bit(#bits_end - 1) {
+                        return None;
+                    }
 
-                    <#field_ty>::try_from_bits(extracted_downcast)
+                    ::bitos::try_extract_field::<#inner_ty, #field_ty>(self.0, #bits_start, #bits_end - 1)
+                }
+            }),
+            FieldTy::Try(field_ty) if bits.invalid.is_some() => {
+                let invalid = bits.invalid.as_ref().unwrap();
+
+                Ok(quote_spanned! {
+                    *span =>
+                    #(#docs)*
+                    #[inline(always)]
+                    #vis fn #field_getter_ident (&self) -> ::core::option::Option<#field_ty> {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        let raw = (<#inner_ty as UnsignedInt>::value(self.0) >> #bits_start)
+                            & ::bitos::integer::mask_to_width(u64::MAX, #bits_end - #bits_start);
+                        if raw == (#invalid) as u64 {
+                            return None;
+                        }
+
+                        ::bitos::try_extract_field::<#inner_ty, #field_ty>(self.0, #bits_start, #bits_end)
+                    }
+                })
+            }
+            FieldTy::Try(field_ty) => Ok(quote_spanned! {
+                *span =>
+                #(#docs)*
+                #[inline(always)]
+                #vis fn #field_getter_ident (&self) -> ::core::option::Option<#field_ty> {
+                    #[allow(unused_imports)]
+                    use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                    const { Self::__assertions() };
+
+                    ::bitos::try_extract_field::<#inner_ty, #field_ty>(self.0, #bits_start, #bits_end)
                 }
             }),
         }
@@ -310,136 +810,604 @@ impl StructField {
             ..
         } = self;
 
-        let bits_start = bits.bitrange.start() as u8;
-        let bits_end = bits.bitrange.end().unwrap_or(bitstruct.bitos_attr.bitlen) as u8;
+        let (bits_start, bits_end) = self.bounds(bitstruct);
+
+        if bits.reserved {
+            return Ok(TokenStream::new());
+        }
 
         let inner_ty = &bitstruct.inner_ty;
         let field_ident_str = ident.to_string();
-        let field_setter_ident = format_ident!("set_{}", ident);
-        let field_with_ident = format_ident!("with_{}", ident);
+        let field_setter_ident = self.accessor_ident("set_", "");
+        let field_with_ident = self.accessor_ident("with_", "");
+
+        // in checked mode, setters return an error instead of writing values outside of the
+        // field's declared range
+        let checked = bitstruct.bitos_attr.checked_setters;
+        let err = quote::quote! { ::bitos::BitosError };
+        let (set_ret, with_ret, set_ok, with_ok, try_) = if checked {
+            (
+                quote::quote! { ::core::result::Result<&mut Self, #err> },
+                quote::quote! { ::core::result::Result<Self, #err> },
+                quote::quote! { Ok(self) },
+                quote::quote! { Ok(self) },
+                quote::quote! { ? },
+            )
+        } else {
+            (
+                quote::quote! { &mut Self },
+                quote::quote! { Self },
+                quote::quote! { self },
+                quote::quote! { self },
+                TokenStream::new(),
+            )
+        };
 
-        match field_ty {
-            FieldTy::Simple(field_ty) => Ok(quote_spanned! {
+        // codec fields only get the basic setters, as every other accessor relies on the field
+        // type implementing `Bits`. encoded values always fit, since the width of the codec's
+        // bits is asserted to match the field's
+        if let Some(codec) = &bits.codec {
+            return Ok(quote_spanned! {
                 *span =>
                 #[doc = "Sets the value of the `"]
                 #[doc = #field_ident_str]
                 #[doc = "` field."]
                 #[inline(always)]
-                #vis fn #field_setter_ident (&mut self, value: #field_ty) -> &mut Self {
-                    #[allow(unused_imports)]
-                    use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                #vis fn #field_setter_ident (&mut self, value: #field_ty) -> #set_ret {
                     const { Self::__assertions() };
 
-                    let value_bits = value.to_bits();
-                    let value_upcast = <#inner_ty as UnsignedInt>::new(
-                        <<#field_ty as TryBits>::Bits as UnsignedInt>::value(value_bits)
-                    );
-
-                    self.0 = self.0.with_bits(#bits_start, #bits_end, value_upcast);
-                    self
+                    let bits = <#codec as ::bitos::FieldCodec<#field_ty>>::encode(value);
+                    self.0 = ::bitos::insert_field(self.0, #bits_start, #bits_end, bits);
+                    #set_ok
                 }
 
                 #[doc = "Consumes `self` to modify the value of the `"]
                 #[doc = #field_ident_str]
                 #[doc = "` field and returns the modified `self`."]
                 #[inline(always)]
-                #vis fn #field_with_ident (mut self, value: #field_ty) -> Self {
-                    self.#field_setter_ident(value);
-                    self
+                #vis fn #field_with_ident (mut self, value: #field_ty) -> #with_ret {
+                    self.#field_setter_ident(value)#try_;
+                    #with_ok
                 }
-            }),
-            FieldTy::Array { elem, len, .. } => {
-                let field_elem_setter_ident = format_ident!("set_{}_at", ident);
-                let field_elem_with_ident = format_ident!("with_{}_at", ident);
-
-                Ok(quote_spanned! {
-                    *span =>
-                    #[doc = "Sets a single element in the `"]
-                    #[doc = #field_ident_str]
-                    #[doc = "` field."]
-                    #[inline(always)]
-                    #vis fn #field_elem_setter_ident (&mut self, index: usize, value: #elem) -> &mut Self {
-                        #[allow(unused_imports)]
-                        use bitos::{TryBits, BitUtils, integer::UnsignedInt};
-                        const { Self::__assertions() };
-
-                        if index < #len {
-                            let elem_len = <#elem as TryBits>::Bits::BITS as u8;
-                            let offset = #bits_start + elem_len * index as u8;
-
-                            let value_bits = value.to_bits();
-                            let value_upcast = <#inner_ty as UnsignedInt>::new(
-                                <<#elem as TryBits>::Bits as UnsignedInt>::value(value_bits)
-                            );
-
-                            self.0 = self.0.with_bits(offset, offset + elem_len, value_upcast);
-                        }
-
-                        self
-                    }
+            });
+        }
 
-                    #[doc = "Consumes `self` to modify the value of a element in the `"]
-                    #[doc = #field_ident_str]
-                    #[doc = "` field and returns the modified `self`."]
-                    #[inline(always)]
-                    #vis fn #field_elem_with_ident (mut self, index: usize, value: #elem) -> Self {
-                        self.#field_elem_setter_ident(index, value);
-                        self
-                    }
+        let range_bounds = self.range_bounds(bitstruct);
 
-                    #[doc = "Sets the value of the `"]
-                    #[doc = #field_ident_str]
-                    #[doc = "` field."]
-                    #[inline(always)]
-                    #vis fn #field_setter_ident (&mut self, value: [#elem; #len]) -> &mut Self{
-                        const { Self::__assertions() };
-                        for (i, elem) in value.into_iter().enumerate() {
-                            self.#field_elem_setter_ident(i, elem);
-                        }
+        let check = |value_bits: TokenStream| {
+            let (min, max) = range_bounds.as_ref()?;
+            Some(quote::quote! {
+                let raw = #value_bits;
+                if !(#min..=#max).contains(&raw) {
+                    return Err(::bitos::BitosError::OutOfRange {
+                        value: raw,
+                        min: #min,
+                        max: #max,
+                    });
+                }
+            })
+        };
 
-                        self
-                    }
+        let toggle = field_ty.is_bool().then(|| {
+            let field_toggle_ident = self.accessor_ident("toggle_", "");
+            let field_toggled_ident = self.accessor_ident("toggled_", "");
 
-                    #[doc = "Consumes `self` to modify the value of the `"]
-                    #[doc = #field_ident_str]
-                    #[doc = "` field and returns the modified `self`."]
-                    #[inline(always)]
-                    #vis fn #field_with_ident (mut self, value: [#elem; #len]) -> Self {
-                        self.#field_setter_ident(value);
-                        self
-                    }
-                })
-            }
-            FieldTy::Try(field_ty) => Ok(quote_spanned! {
+            quote_spanned! {
                 *span =>
-                #[doc = "Sets the value of the `"]
+                #[doc = "Flips the value of the `"]
                 #[doc = #field_ident_str]
                 #[doc = "` field."]
                 #[inline(always)]
-                #vis fn #field_setter_ident (&mut self, value: #field_ty) -> &mut Self {
+                #vis fn #field_toggle_ident (&mut self) -> &mut Self {
                     #[allow(unused_imports)]
-                    use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                    use bitos::BitUtils;
                     const { Self::__assertions() };
 
-                    let value_bits = value.to_bits();
-                    let value_upcast = <#inner_ty as UnsignedInt>::new(
-                        <<#field_ty as TryBits>::Bits as UnsignedInt>::value(value_bits)
-                    );
-
-                    self.0 = self.0.with_bits(#bits_start, #bits_end, value_upcast);
+                    self.0 = self.0.with_bit(#bits_start, !self.0.bit(#bits_start));
                     self
                 }
 
-                #[doc = "Consumes `self` to modify the value of the `"]
+                #[doc = "Consumes `self` to flip the value of the `"]
                 #[doc = #field_ident_str]
                 #[doc = "` field and returns the modified `self`."]
                 #[inline(always)]
-                #vis fn #field_with_ident (mut self, value: #field_ty) -> Self {
-                    self.#field_setter_ident(value);
+                #vis fn #field_toggled_ident (mut self) -> Self {
+                    self.#field_toggle_ident();
                     self
                 }
-            }),
-        }
+            }
+        });
+
+        let modify = matches!(field_ty, FieldTy::Simple(_)).then(|| {
+            let field_getter_ident = self.accessor_ident("", "");
+            let field_modify_ident = self.accessor_ident("modify_", "");
+
+            quote_spanned! {
+                *span =>
+                #[doc = "Reads the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field, applies `f` to it and writes the result back."]
+                #[inline(always)]
+                #vis fn #field_modify_ident (
+                    &mut self,
+                    f: impl ::core::ops::FnOnce(#field_ty) -> #field_ty,
+                ) -> #set_ret {
+                    let value = f(self.#field_getter_ident());
+                    self.#field_setter_ident(value)#try_;
+                    #set_ok
+                }
+            }
+        });
+
+        let saturating_add = field_ty.is_signed().then(|| {
+            let field_getter_ident = self.accessor_ident("", "");
+            let field_saturating_add_ident = self.accessor_ident("saturating_add_to_", "");
+
+            quote_spanned! {
+                *span =>
+                #[doc = "Adds `delta` to the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field, saturating at the bounds of the field instead of overflowing."]
+                #[inline(always)]
+                #vis fn #field_saturating_add_ident (&mut self, delta: #field_ty) -> #set_ret {
+                    let value = self.#field_getter_ident().saturating_add(delta);
+                    self.#field_setter_ident(value)#try_;
+                    #set_ok
+                }
+            }
+        });
+
+        let clamp = range_bounds.as_ref().map(|(min, max)| {
+            let field_clamp_ident = self.accessor_ident("clamp_", "");
+
+            quote_spanned! {
+                *span =>
+                #[doc = "Clamps the raw value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field into its declared range."]
+                #[inline(always)]
+                #vis fn #field_clamp_ident (&mut self) -> &mut Self {
+                    #[allow(unused_imports)]
+                    use bitos::{BitUtils, integer::UnsignedInt};
+                    const { Self::__assertions() };
+
+                    let raw = <#inner_ty as UnsignedInt>::value(self.0.bits(#bits_start, #bits_end));
+                    let clamped = raw.clamp(#min, #max);
+                    self.0 = self.0.with_bits(
+                        #bits_start,
+                        #bits_end,
+                        <#inner_ty as UnsignedInt>::new(clamped),
+                    );
+
+                    self
+                }
+            }
+        });
+
+        let replace = {
+            let field_replace_ident = self.accessor_ident("replace_", "");
+            let phantom_data = &bitstruct.phantom_data;
+
+            quote_spanned! {
+                *span =>
+                #[doc = "Returns a copy of `self` with the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field replaced, leaving `self` untouched."]
+                #[inline(always)]
+                #vis fn #field_replace_ident (&self, value: #field_ty) -> #with_ret {
+                    Self(self.0, #phantom_data).#field_with_ident(value)
+                }
+            }
+        };
+
+        // fields with a presence bit are set from an `Option`, with `None` clearing the whole
+        // field. fields with an invalid sentinel are too, with `None` writing the sentinel
+        if let FieldTy::Try(field_ty) = field_ty
+            && (bits.with_presence || bits.invalid.is_some())
+        {
+            let field_replace_ident = self.accessor_ident("replace_", "");
+            let phantom_data = &bitstruct.phantom_data;
+
+            // a value encoding to the sentinel would read back as `None`, so it is rejected
+            let sentinel_check = bits.invalid.as_ref().map(|invalid| {
+                let raw = quote::quote! {
+                    <<#field_ty as TryBits>::Bits as UnsignedInt>::value(
+                        <#field_ty as TryBits>::to_bits(&value),
+                    )
+                };
+
+                if checked {
+                    quote::quote! {
+                        if #raw == (#invalid) as u64 {
+                            return Err(::bitos::BitosError::InvalidBitPattern);
+                        }
+                    }
+                } else {
+                    let msg = format!(
+                        "value of field '{field_ident_str}' collides with its invalid sentinel"
+                    );
+                    quote::quote! {
+                        assert!(#raw != (#invalid) as u64, #msg);
+                    }
+                }
+            });
+
+            let (set_doc, set_some, set_none) = match &bits.invalid {
+                Some(invalid) => (
+                    "` field, writing its invalid sentinel if `value` is [`None`]. Values encoding to the sentinel are rejected.",
+                    quote::quote! {
+                        #sentinel_check
+                        self.0 = ::bitos::insert_field(self.0, #bits_start, #bits_end, value);
+                    },
+                    quote::quote! {
+                        self.0 = self.0.with_bits(
+                            #bits_start,
+                            #bits_end,
+                            <#inner_ty as UnsignedInt>::new((#invalid) as u64),
+                        );
+                    },
+                ),
+                None => (
+                    "` field, setting its presence bit if `value` is [`Some`] and clearing the whole field otherwise.",
+                    quote::quote! {
+                        self.0 = ::bitos::insert_field(self.0, #bits_start, #bits_end - 1, value);
+                        self.0 = self.0.with_bit(#bits_end - 1, true);
+                    },
+                    quote::quote! {
+                        self.0 = self.0.with_bits(
+                            #bits_start,
+                            #bits_end,
+                            <#inner_ty as UnsignedInt>::new(0),
+                        );
+                    },
+                ),
+            };
+
+            return Ok(quote_spanned! {
+                *span =>
+                #[doc = "Sets the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = #set_doc]
+                #[inline(always)]
+                #vis fn #field_setter_ident (&mut self, value: ::core::option::Option<#field_ty>) -> #set_ret {
+                    #[allow(unused_imports)]
+                    use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                    const { Self::__assertions() };
+
+                    match value {
+                        Some(value) => {
+                            #set_some
+                        }
+                        None => {
+                            #set_none
+                        }
+                    }
+
+                    #set_ok
+                }
+
+                #[doc = "Consumes `self` to modify the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field and returns the modified `self`."]
+                #[inline(always)]
+                #vis fn #field_with_ident (mut self, value: ::core::option::Option<#field_ty>) -> #with_ret {
+                    self.#field_setter_ident(value)#try_;
+                    #with_ok
+                }
+
+                #[doc = "Returns a copy of `self` with the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field replaced, leaving `self` untouched."]
+                #[inline(always)]
+                #vis fn #field_replace_ident (&self, value: ::core::option::Option<#field_ty>) -> #with_ret {
+                    Self(self.0, #phantom_data).#field_with_ident(value)
+                }
+            });
+        }
+
+        // const accessors operate on raw integers directly, as trait methods can't be called in
+        // const contexts
+        let const_accessors = field_ty.const_width().map(|width| {
+            let field_const_getter_ident = self.accessor_ident("", "_const");
+            let field_const_with_ident = self.accessor_ident("with_", "_const");
+            let phantom_data = &bitstruct.phantom_data;
+
+            let bitlen = bitstruct.bitos_attr.bitlen;
+            let primitive = |bits: usize| matches!(bits, 8 | 16 | 32 | 64);
+            let (inner_raw, inner_new) = if primitive(bitlen) {
+                let prim = format_ident!("u{}", bitlen);
+                (
+                    quote::quote! { self.0 as u64 },
+                    quote::quote! { raw as #prim },
+                )
+            } else {
+                let storage = format_ident!("u{}", bitlen.next_power_of_two().max(8));
+                (
+                    quote::quote! { self.0.const_value() as u64 },
+                    quote::quote! { #inner_ty::const_new(raw as #storage) },
+                )
+            };
+
+            let is_bool = field_ty.is_bool();
+            let (value_raw, value_new) = if is_bool || primitive(width) {
+                let from_raw = if is_bool {
+                    quote::quote! { raw != 0 }
+                } else {
+                    let prim = format_ident!("u{}", width);
+                    quote::quote! { raw as #prim }
+                };
+
+                (quote::quote! { value as u64 }, from_raw)
+            } else {
+                let uint = format_ident!("u{}", width);
+                let storage = format_ident!("u{}", width.next_power_of_two().max(8));
+                (
+                    quote::quote! { value.const_value() as u64 },
+                    quote::quote! { ::bitos::integer::#uint::const_new(raw as #storage) },
+                )
+            };
+
+            let const_getter_body = if is_bool {
+                quote::quote! { ::bitos::bit_at(#inner_raw, #bits_start) }
+            } else {
+                quote::quote! {
+                    let raw = (#inner_raw >> #bits_start)
+                        & ::bitos::integer::mask_to_width(u64::MAX, #bits_end - #bits_start);
+                    #value_new
+                }
+            };
+
+            // the const setter can't report errors, so it isn't generated in checked mode. values
+            // outside of the declared range panic, which fails const evaluation
+            let range_assertion = range_bounds.as_ref().map(|(min, max)| {
+                let msg =
+                    format!("value of field '{field_ident_str}' is outside of its declared range");
+                quote::quote! {
+                    let raw_value = #value_raw;
+                    assert!(raw_value >= #min && raw_value <= #max, #msg);
+                }
+            });
+            let const_with = (!checked).then(|| {
+                quote::quote! {
+                    #[doc = "Consumes `self` to modify the value of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field in a const context and returns the modified `self`."]
+                    #[inline(always)]
+                    #vis const fn #field_const_with_ident (self, value: #field_ty) -> Self {
+                        const { Self::__assertions() };
+
+                        #range_assertion
+                        let value = #value_raw
+                            & ::bitos::integer::mask_to_width(u64::MAX, #bits_end - #bits_start);
+                        let raw = (#inner_raw
+                            & !::bitos::integer::range_mask(#bits_start, #bits_end))
+                            | (value << #bits_start);
+                        Self(#inner_new, #phantom_data)
+                    }
+                }
+            });
+
+            quote_spanned! {
+                *span =>
+                #[doc = "Gets the value of the `"]
+                #[doc = #field_ident_str]
+                #[doc = "` field in a const context."]
+                #[inline(always)]
+                #vis const fn #field_const_getter_ident (&self) -> #field_ty {
+                    const { Self::__assertions() };
+                    #const_getter_body
+                }
+
+                #const_with
+            }
+        });
+
+        match field_ty {
+            FieldTy::Simple(field_ty) | FieldTy::Try(field_ty) => {
+                let field_try_setter_ident = self.accessor_ident("try_set_", "");
+                let check = check(
+                    quote::quote! { <<#field_ty as TryBits>::Bits as UnsignedInt>::value(value.to_bits()) },
+                );
+                let checked_check = check.as_ref().filter(|_| checked);
+                // only fields with a declared range have values which can be rejected
+                let try_setter = check.as_ref().map(|check| {
+                    quote_spanned! {
+                        *span =>
+                        #[doc = "Sets the value of the `"]
+                        #[doc = #field_ident_str]
+                        #[doc = "` field, returning an error if the value is outside of its declared"]
+                        #[doc = "range."]
+                        #[inline(always)]
+                        #vis fn #field_try_setter_ident (&mut self, value: #field_ty) -> ::core::result::Result<&mut Self, #err> {
+                            #[allow(unused_imports)]
+                            use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                            const { Self::__assertions() };
+
+                            #check
+                            self.0 = ::bitos::insert_field(self.0, #bits_start, #bits_end, value);
+                            Ok(self)
+                        }
+                    }
+                });
+
+                Ok(quote_spanned! {
+                    *span =>
+                    #[doc = "Sets the value of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field."]
+                    #[inline(always)]
+                    #vis fn #field_setter_ident (&mut self, value: #field_ty) -> #set_ret {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        #checked_check
+                        self.0 = ::bitos::insert_field(self.0, #bits_start, #bits_end, value);
+                        #set_ok
+                    }
+
+                    #[doc = "Consumes `self` to modify the value of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field and returns the modified `self`."]
+                    #[inline(always)]
+                    #vis fn #field_with_ident (mut self, value: #field_ty) -> #with_ret {
+                        self.#field_setter_ident(value)#try_;
+                        #with_ok
+                    }
+
+                    #try_setter
+                    #replace
+                    #modify
+                    #toggle
+                    #saturating_add
+                    #clamp
+                    #const_accessors
+                })
+            }
+            FieldTy::Array { elem, len, .. } => {
+                let field_elem_setter_ident = self.accessor_ident("set_", "_at");
+                let field_elem_with_ident = self.accessor_ident("with_", "_at");
+                // checked setters must validate every element, so they go through the element
+                // setter instead of packing the whole array at once
+                let pack_array = if checked {
+                    quote::quote! {
+                        for (i, elem) in value.into_iter().enumerate() {
+                            self.#field_elem_setter_ident(i, elem)?;
+                        }
+                    }
+                } else {
+                    quote::quote! {
+                        let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+                        let packed = ::bitos::chunks::join_chunks::<{ #len }>(
+                            <#inner_ty as UnsignedInt>::value(self.0),
+                            #bits_start,
+                            elem_len,
+                            value.map(|elem| {
+                                <<#elem as TryBits>::Bits as UnsignedInt>::value(elem.to_bits())
+                            }),
+                        );
+
+                        self.0 = <#inner_ty as UnsignedInt>::new(packed);
+                    }
+                };
+                let field_splat_ident = self.accessor_ident("with_", "_splat");
+                // individually named element accessors, from `stride` and `count`
+                let named_setters = bits.count.map(|count| {
+                    let accessors = (0..count).map(|index| {
+                        let setter_ident = self.accessor_ident("set_", &index.to_string());
+                        let with_ident = self.accessor_ident("with_", &index.to_string());
+                        let set_doc = format!("Sets element {index} of the `{field_ident_str}` field.");
+                        let with_doc = format!(
+                            "Consumes `self` to modify element {index} of the `{field_ident_str}` field and returns the modified `self`."
+                        );
+
+                        quote_spanned! {
+                            *span =>
+                            #[doc = #set_doc]
+                            #[inline(always)]
+                            #vis fn #setter_ident (&mut self, value: #elem) -> #set_ret {
+                                self.#field_elem_setter_ident(#index, value)
+                            }
+
+                            #[doc = #with_doc]
+                            #[inline(always)]
+                            #vis fn #with_ident (self, value: #elem) -> #with_ret {
+                                self.#field_elem_with_ident(#index, value)
+                            }
+                        }
+                    });
+
+                    quote::quote! { #(#accessors)* }
+                });
+                let out_of_bounds = checked.then(|| {
+                    quote::quote! {
+                        else {
+                            return Err(::bitos::BitosError::IndexOutOfBounds { index, len: #len });
+                        }
+                    }
+                });
+
+                Ok(quote_spanned! {
+                    *span =>
+                    #[doc = "Sets a single element in the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field."]
+                    #[inline(always)]
+                    #vis fn #field_elem_setter_ident (&mut self, index: usize, value: #elem) -> #set_ret {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, BitUtils, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        if index < #len {
+                            let elem_len = <#elem as ::bitos::BitWidth>::BITS as u8;
+
+                            let packed = ::bitos::chunks::with_chunk(
+                                <#inner_ty as UnsignedInt>::value(self.0),
+                                #bits_start,
+                                elem_len,
+                                index,
+                                <<#elem as TryBits>::Bits as UnsignedInt>::value(value.to_bits()),
+                            );
+
+                            self.0 = <#inner_ty as UnsignedInt>::new(packed);
+                        } #out_of_bounds
+
+                        #set_ok
+                    }
+
+                    #[doc = "Consumes `self` to modify the value of a element in the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field and returns the modified `self`."]
+                    #[inline(always)]
+                    #vis fn #field_elem_with_ident (mut self, index: usize, value: #elem) -> #with_ret {
+                        self.#field_elem_setter_ident(index, value)#try_;
+                        #with_ok
+                    }
+
+                    #[doc = "Sets the value of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field."]
+                    #[inline(always)]
+                    #vis fn #field_setter_ident (&mut self, value: [#elem; #len]) -> #set_ret {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, integer::UnsignedInt};
+                        const { Self::__assertions() };
+
+                        #pack_array
+                        #set_ok
+                    }
+
+                    #[doc = "Consumes `self` to modify the value of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field and returns the modified `self`."]
+                    #[inline(always)]
+                    #vis fn #field_with_ident (mut self, value: [#elem; #len]) -> #with_ret {
+                        self.#field_setter_ident(value)#try_;
+                        #with_ok
+                    }
+
+                    #[doc = "Consumes `self` to set every element of the `"]
+                    #[doc = #field_ident_str]
+                    #[doc = "` field to `value` and returns the modified `self`."]
+                    #[inline(always)]
+                    #vis fn #field_splat_ident (mut self, value: #elem) -> #with_ret {
+                        #[allow(unused_imports)]
+                        use bitos::{TryBits, Bits};
+
+                        // elements aren't necessarily `Copy`, but their raw bits are
+                        let value_bits = value.to_bits();
+                        for index in 0..#len {
+                            self.#field_elem_setter_ident(index, <#elem>::from_bits(value_bits))#try_;
+                        }
+
+                        #with_ok
+                    }
+
+                    #named_setters
+                    #replace
+                })
+            }
+        }
     }
 }
 
@@ -461,30 +1429,57 @@ impl BitStruct {
         let inner_ty =
             Box::new(parse_quote_spanned! { bitos_attr.span => ::bitos::integer::#inner_ty_name });
 
+        let positional = match &s.fields {
+            syn::Fields::Unnamed(unnamed) if unnamed.unnamed.len() != 1 => {
+                return Err(Error::new(
+                    unnamed.span(),
+                    "tuple structs must have exactly one field",
+                ));
+            }
+            syn::Fields::Unnamed(_) => true,
+            _ => false,
+        };
+
+        // the plain companion struct can't tell which generics its fields use, and unused ones
+        // would be rejected by the compiler
+        if bitos_attr.plain && !s.generics.params.is_empty() {
+            let option = bitos_attr.options.iter().find(|o| *o == "plain").unwrap();
+            return Err(Error::new(
+                option.span(),
+                "option 'plain' is not supported on generic structs",
+            ));
+        }
+
         let mut fields = Vec::new();
-        let fields_err =
-            s.fields
-                .iter()
-                .map(StructField::new)
-                .fold(None, |acc: Option<Error>, r| match r {
-                    Ok(f) => {
-                        fields.push(f);
-                        acc
-                    }
-                    Err(e) => {
-                        if let Some(mut acc) = acc {
-                            acc.combine(e);
-                            Some(acc)
-                        } else {
-                            Some(e)
-                        }
+        let fields_err = s
+            .fields
+            .iter()
+            .map(|f| StructField::new(f, positional))
+            .fold(None, |acc: Option<Error>, r| match r {
+                Ok(f) => {
+                    fields.push(f);
+                    acc
+                }
+                Err(e) => {
+                    if let Some(mut acc) = acc {
+                        acc.combine(e);
+                        Some(acc)
+                    } else {
+                        Some(e)
                     }
-                });
+                }
+            });
 
         if let Some(e) = fields_err {
             return Err(e);
         }
 
+        for field in &mut fields {
+            if field.bits.msb0.unwrap_or(bitos_attr.msb0) {
+                field.bits.bitrange = field.bits.bitrange.mirrored(bitos_attr.bitlen);
+            }
+        }
+
         let generics = &s.generics;
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let ty_params = generics
@@ -502,9 +1497,154 @@ impl BitStruct {
             phantom_data,
         };
 
+        let mut empty_err: Option<Error> = None;
+        for field in &fields {
+            if let Some(range) = field.bitrange(&bitstruct)
+                && range.start >= range.end
+            {
+                let message = if range.start > range.end {
+                    format!(
+                        "field '{}' has swapped bit range bounds ({}..{}): start must be less than end, did you mean {}..{}?",
+                        field.ident, range.start, range.end, range.end, range.start
+                    )
+                } else {
+                    format!(
+                        "field '{}' has an empty bit range ({}..{})",
+                        field.ident, range.start, range.end
+                    )
+                };
+                let e = Error::new(field.bits.span, message);
+
+                match &mut empty_err {
+                    Some(acc) => acc.combine(e),
+                    None => empty_err = Some(e),
+                }
+            }
+        }
+
+        if let Some(e) = empty_err {
+            return Err(e);
+        }
+
+        // layout checks of fields with bounds given as const expressions are left to the
+        // generated code
+        let mut layout_assertions: Vec<Expr> = Vec::new();
+        let mut overlap_err: Option<Error> = None;
+        for (i, a) in fields.iter().enumerate() {
+            for b in &fields[i + 1..] {
+                if a.bits.overlaps_ok || b.bits.overlaps_ok {
+                    continue;
+                }
+
+                let (Some(range_a), Some(range_b)) =
+                    (a.bitrange(&bitstruct), b.bitrange(&bitstruct))
+                else {
+                    let (a_start, a_end) = a.bounds(&bitstruct);
+                    let (b_start, b_end) = b.bounds(&bitstruct);
+                    let msg = format!("field '{}' overlaps with field '{}'", b.ident, a.ident);
+                    layout_assertions.push(parse_quote! {
+                        assert!(!(#a_start < #b_end && #b_start < #a_end), #msg)
+                    });
+
+                    continue;
+                };
+
+                if range_a.start < range_b.end && range_b.start < range_a.end {
+                    let e = Error::new(
+                        b.bits.span,
+                        format!(
+                            "field '{}' ({}..{}) overlaps with field '{}' ({}..{})",
+                            b.ident,
+                            range_b.start,
+                            range_b.end,
+                            a.ident,
+                            range_a.start,
+                            range_a.end
+                        ),
+                    );
+
+                    match &mut overlap_err {
+                        Some(acc) => acc.combine(e),
+                        None => overlap_err = Some(e),
+                    }
+                }
+            }
+        }
+
+        if let Some(e) = overlap_err {
+            return Err(e);
+        }
+
+        let all_literal = fields.iter().all(|f| f.bitrange(&bitstruct).is_some());
+        if bitstruct.bitos_attr.exhaustive && !all_literal {
+            let bitlen = bitstruct.bitos_attr.bitlen as u8;
+            let field_masks = fields.iter().map(|f| {
+                let (start, end) = f.bounds(&bitstruct);
+                quote::quote! { ::bitos::integer::range_mask(#start, #end) }
+            });
+
+            layout_assertions.push(parse_quote! {
+                assert!(
+                    (0u64 #(| #field_masks)*) == ::bitos::integer::range_mask(0, #bitlen),
+                    "not every bit is covered by some field"
+                )
+            });
+        } else if bitstruct.bitos_attr.exhaustive {
+            let mut covered = vec![false; bitstruct.bitos_attr.bitlen];
+            for range in fields.iter().filter_map(|f| f.bitrange(&bitstruct)) {
+                for bit in range.start..range.end.min(covered.len()) {
+                    covered[bit] = true;
+                }
+            }
+
+            let mut gaps_err: Option<Error> = None;
+            let mut bit = 0;
+            while bit < covered.len() {
+                if covered[bit] {
+                    bit += 1;
+                    continue;
+                }
+
+                let start = bit;
+                while bit < covered.len() && !covered[bit] {
+                    bit += 1;
+                }
+
+                let e = Error::new(
+                    bitstruct.bitos_attr.span,
+                    format!("bits {start}..{bit} are not covered by any field"),
+                );
+
+                match &mut gaps_err {
+                    Some(acc) => acc.combine(e),
+                    None => gaps_err = Some(e),
+                }
+            }
+
+            if let Some(e) = gaps_err {
+                return Err(e);
+            }
+        }
+
+        for other in &bitstruct.bitos_attr.transmute_from {
+            let bitlen = bitstruct.bitos_attr.bitlen;
+            let msg = format!(
+                "`transmute_from` type '{}' does not have the same bit width",
+                other.to_token_stream()
+            );
+
+            layout_assertions.push(parse_quote_spanned! {
+                other.span() =>
+                {
+                    assert!(<#other as ::bitos::BitWidth>::BITS == #bitlen, #msg);
+                }
+            });
+        }
+
         let assertions = fields
             .iter()
             .map(|f| f.assertions(&bitstruct))
+            .chain(layout_assertions)
             .collect::<Vec<_>>();
 
         let masks = fields
@@ -522,12 +1662,82 @@ impl BitStruct {
             .map(|f| f.setters(&bitstruct))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let fields_mask = {
+            let mask_idents = fields
+                .iter()
+                .filter(|f| !f.bits.reserved)
+                .map(|f| format_ident!("{}_MASK", f.ident.to_string().to_shouty_snake_case()));
+
+            quote::quote! { 0u64 #(| Self::#mask_idents)* }
+        };
+
+        // a single validity predicate per fallible field drives `is_valid`, `try_from_valid` and
+        // `invalid_fields_mask`. fields with an explicit presence bit or invalid sentinel are also
+        // valid when empty
+        let (validity_mask_idents, validity_checks): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .filter(|f| !f.bits.reserved && matches!(f.ty, FieldTy::Try(_)))
+            .map(|f| {
+                let inner_ty = &bitstruct.inner_ty;
+                let mask_ident =
+                    format_ident!("{}_MASK", f.ident.to_string().to_shouty_snake_case());
+                let getter_ident = f.accessor_ident("", "");
+                let (start, end) = f.bounds(&bitstruct);
+                let check = if f.bits.with_presence {
+                    quote::quote! { (!self.0.bit(#end - 1) || self.#getter_ident().is_some()) }
+                } else if let Some(invalid) = &f.bits.invalid {
+                    quote::quote! {
+                        (self.#getter_ident().is_some()
+                            || ((<#inner_ty as UnsignedInt>::value(self.0) >> #start)
+                                & ::bitos::integer::mask_to_width(u64::MAX, #end - #start))
+                                == (#invalid) as u64)
+                    }
+                } else {
+                    quote::quote! { self.#getter_ident().is_some() }
+                };
+
+                (mask_ident, check)
+            })
+            .unzip();
+
+        // derives as written by the user, before the ones implemented on the raw bits are
+        // extracted, for the plain companion struct
+        let user_derives = s
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("derive"))
+            .cloned()
+            .collect::<Vec<_>>();
+
         let generate_debug = extract_derive("Debug", &mut s.attrs);
+        let generate_clone = extract_derive("Clone", &mut s.attrs);
+        let generate_copy = extract_derive("Copy", &mut s.attrs);
+        let generate_hash = extract_derive("Hash", &mut s.attrs);
+        let generate_partial_eq = extract_derive("PartialEq", &mut s.attrs);
+        let generate_eq = extract_derive("Eq", &mut s.attrs);
+        let generate_partial_ord = extract_derive("PartialOrd", &mut s.attrs);
+        let generate_ord = extract_derive("Ord", &mut s.attrs);
+
+        // a derived `Default` would ignore field defaults, so implement it through the reset value
+        let has_defaults = fields.iter().any(|f| f.bits.default.is_some());
+        let generate_default = has_defaults && extract_derive("Default", &mut s.attrs);
+
+        let reset_value = {
+            let defaults = fields.iter().filter_map(|f| {
+                let default = f.bits.default.as_ref()?;
+                let (start, _) = f.bounds(&bitstruct);
+                Some(quote::quote! { | (((#default) as u64) << #start) })
+            });
+
+            quote::quote! { 0u64 #(#defaults)* }
+        };
 
         let attrs = &s.attrs;
         let vis = &s.vis;
         let ident = &s.ident;
         let inner_ty = &bitstruct.inner_ty;
+        let signed_ty = format_ident!("i{}", bitstruct.bitos_attr.bitlen);
+        let signed_ty = quote::quote! { ::bitos::integer::#signed_ty };
         let phantom_data = &bitstruct.phantom_data;
 
         let zerocopy = if cfg!(feature = "zerocopy") {
@@ -558,33 +1768,182 @@ impl BitStruct {
             impl #impl_generics #ident #ty_generics #where_clause {
                 #(#masks)*
 
+                /// Mask where only bits of non-reserved fields are set.
+                pub const FIELDS_MASK: u64 = #fields_mask;
+
                 #[doc(hidden)]
                 const fn __assertions() {
                     #(#assertions)*
                 }
 
                 #[inline(always)]
-                pub fn from_bits(value: <Self as ::bitos::TryBits>::Bits) -> Self {
+                pub const fn from_bits(value: <Self as ::bitos::TryBits>::Bits) -> Self {
                     const { Self::__assertions() };
                     Self(value, #phantom_data)
                 }
 
+                /// Returns the backing integer of this value.
                 #[inline(always)]
-                pub fn to_bits(&self) -> <Self as ::bitos::TryBits>::Bits {
-                    const { Self::__assertions() };
+                pub fn inner(&self) -> #inner_ty {
                     self.0
                 }
 
-                #(#getters)*
-                #(#setters)*
-            }
-        };
+                /// Returns a mutable reference to the backing integer of this value.
+                ///
+                /// Writing through this reference bypasses any validation done by the field
+                /// setters, including the protection of reserved bits.
+                #[inline(always)]
+                pub fn inner_mut(&mut self) -> &mut #inner_ty {
+                    &mut self.0
+                }
 
-        let dbg = generate_debug.then(|| {
-            let ty_ident_str = ident.to_string();
-            let field_idents = fields.iter().map(|f| &f.ident);
-            let field_idents_str = fields.iter().map(|f| f.ident.to_string());
-            let mut generics = generics.clone();
+                /// Creates a value of this type from its raw bits, returning [`None`] if any
+                /// fallible field holds an invalid bit pattern.
+                #[inline(always)]
+                pub fn try_from_valid(
+                    value: <Self as ::bitos::TryBits>::Bits,
+                ) -> ::core::option::Option<Self> {
+                    let this = Self::from_bits(value);
+                    this.is_valid().then_some(this)
+                }
+
+                /// Returns whether every fallible field holds a valid bit pattern.
+                #[inline(always)]
+                pub fn is_valid(&self) -> bool {
+                    #[allow(unused_imports)]
+                    use ::bitos::{BitUtils, integer::UnsignedInt};
+
+                    true #(&& #validity_checks)*
+                }
+
+                /// Returns a mask where the bits of every fallible field holding an invalid bit
+                /// pattern are set.
+                #[inline(always)]
+                pub fn invalid_fields_mask(&self) -> <Self as ::bitos::TryBits>::Bits {
+                    #[allow(unused_imports)]
+                    use ::bitos::{BitUtils, integer::UnsignedInt};
+
+                    #[allow(unused_mut)]
+                    let mut mask = 0u64;
+                    #(
+                        if !#validity_checks {
+                            mask |= Self::#validity_mask_idents;
+                        }
+                    )*
+
+                    <<Self as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::new(mask)
+                }
+
+                #[inline(always)]
+                pub fn to_bits(&self) -> <Self as ::bitos::TryBits>::Bits {
+                    const { Self::__assertions() };
+                    self.0
+                }
+
+                /// Returns the bits of `raw` which fall outside of any non-reserved field, i.e.
+                /// `raw & !FIELDS_MASK`.
+                #[inline(always)]
+                pub fn reserved_bits(
+                    raw: <Self as ::bitos::TryBits>::Bits,
+                ) -> <Self as ::bitos::TryBits>::Bits {
+                    <<Self as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::new(
+                        <<Self as ::bitos::TryBits>::Bits as ::bitos::integer::UnsignedInt>::value(raw)
+                            & !Self::FIELDS_MASK,
+                    )
+                }
+
+                /// Clears all fields of this value, including reserved ones, by setting every bit
+                /// to zero.
+                #[inline(always)]
+                pub fn clear(&mut self) -> &mut Self {
+                    self.0 = <#inner_ty as ::bitos::integer::UnsignedInt>::new(0);
+                    self
+                }
+
+                /// Consumes `self` to apply `f` to it and returns the modified `self`. Useful for
+                /// conditional updates which don't fit in a chain of `with_*` calls.
+                #[inline(always)]
+                pub fn update(mut self, f: impl FnOnce(&mut Self)) -> Self {
+                    f(&mut self);
+                    self
+                }
+
+                /// Returns a value where the bits set in either `self` or `other` are set.
+                #[inline(always)]
+                pub fn union(self, other: Self) -> Self {
+                    Self(self.0 | other.0, #phantom_data)
+                }
+
+                /// Returns a value where only the bits set in both `self` and `other` are set.
+                #[inline(always)]
+                pub fn intersection(self, other: Self) -> Self {
+                    Self(self.0 & other.0, #phantom_data)
+                }
+
+                /// Returns a value where only the bits set in `self` but not in `other` are set.
+                #[inline(always)]
+                pub fn difference(self, other: Self) -> Self {
+                    Self(self.0 & !other.0, #phantom_data)
+                }
+
+                /// Returns a copy of this value where only the bits set in any of the given
+                /// masks, e.g. [`Self::FIELDS_MASK`] or a field's `_MASK`, are kept.
+                #[inline(always)]
+                pub fn project(&self, masks: &[u64]) -> Self {
+                    use ::bitos::integer::UnsignedInt;
+
+                    let mask = masks.iter().fold(0u64, |acc, mask| acc | mask);
+                    let value = <#inner_ty as UnsignedInt>::value(self.0) & mask;
+                    Self(<#inner_ty as UnsignedInt>::new(value), #phantom_data)
+                }
+
+                /// Interprets the raw bits of this value as a two's complement signed integer of
+                /// the same width.
+                #[inline(always)]
+                pub fn as_signed(&self) -> #signed_ty {
+                    <#signed_ty as ::bitos::Bits>::from_bits(self.0)
+                }
+
+                /// Creates a new value with every field set to its reset value, as declared by
+                /// `default = ..` in its `#[bits(..)]` attribute. Fields without a declared
+                /// default are zero.
+                #[inline(always)]
+                pub fn new_reset() -> Self {
+                    const { Self::__assertions() };
+                    Self(
+                        <#inner_ty as ::bitos::integer::UnsignedInt>::new(#reset_value),
+                        #phantom_data,
+                    )
+                }
+
+                /// Resets all fields of this value, including reserved ones, to their reset
+                /// value. See [`Self::new_reset`].
+                #[inline(always)]
+                pub fn reset(&mut self) -> &mut Self {
+                    *self = Self::new_reset();
+                    self
+                }
+
+                #(#getters)*
+                #(#setters)*
+            }
+        };
+
+        let dbg = generate_debug.then(|| {
+            let ty_ident_str = ident.to_string();
+            let field_idents_str = fields.iter().map(|f| f.ident.to_string());
+            let field_values = fields.iter().map(|f| {
+                let ident = f.accessor_ident("", "");
+                if f.bits.reserved {
+                    quote::quote! { &::core::format_args!("reserved") }
+                } else {
+                    quote::quote! { &self.#ident() }
+                }
+            });
+            let raw_field = bitstruct.bitos_attr.debug_raw.then(|| {
+                quote::quote! { .field("raw", &::core::format_args!("{:#X}", self.0)) }
+            });
+            let mut generics = generics.clone();
             for param in generics.type_params_mut() {
                 param.bounds.push(parse_quote! { ::core::fmt::Debug });
             }
@@ -597,15 +1956,815 @@ impl BitStruct {
                     #[inline]
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                         f.debug_struct(#ty_ident_str)
-                            #(.field(#field_idents_str, &self.#field_idents()))*
+                            #raw_field
+                            #(.field(#field_idents_str, #field_values))*
                             .finish()
                     }
                 }
             }
         });
 
+        let field_id = bitstruct.bitos_attr.field_id.then(|| {
+            let field_id_ident = format_ident!("{}FieldId", ident);
+            let field_id_doc = format!("Identifies a field of [`{ident}`].");
+            let accessible = fields
+                .iter()
+                .filter(|f| !f.bits.reserved)
+                .collect::<Vec<_>>();
+            let variant_idents = accessible
+                .iter()
+                .map(|f| format_ident!("{}", f.ident.to_string().to_upper_camel_case()))
+                .collect::<Vec<_>>();
+            let variant_docs = accessible
+                .iter()
+                .map(|f| format!("The `{}` field.", f.ident));
+            let starts = accessible.iter().map(|f| {
+                let (start, _) = f.bounds(&bitstruct);
+                quote::quote! { #start as u32 }
+            });
+            let value_masks = accessible.iter().map(|f| {
+                let (start, end) = f.bounds(&bitstruct);
+                quote::quote! {
+                    ::bitos::integer::mask_to_width(u64::MAX, #end.saturating_sub(#start))
+                }
+            });
+
+            let names = accessible
+                .iter()
+                .map(|f| f.ident.to_string())
+                .collect::<Vec<_>>();
+
+            let nested = accessible
+                .iter()
+                .filter(|f| f.bits.nested)
+                .collect::<Vec<_>>();
+            let nested_names = nested
+                .iter()
+                .map(|f| f.ident.to_string())
+                .collect::<Vec<_>>();
+            let nested_getters = nested
+                .iter()
+                .map(|f| f.accessor_ident("", ""))
+                .collect::<Vec<_>>();
+            let nested_setters = nested.iter().map(|f| f.accessor_ident("set_", ""));
+
+            let from_str = bitstruct.bitos_attr.parse.then(|| {
+                quote::quote! {
+                    #[allow(clippy::all)]
+                    impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+                        type Err = ::bitos::ParseFieldsError;
+
+                        /// Parses a value from a comma separated list of `field=value` pairs. Fields
+                        /// which are not listed are zero.
+                        fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                            let mut value = Self(
+                                <#inner_ty as ::bitos::integer::UnsignedInt>::new(0),
+                                #phantom_data,
+                            );
+
+                            for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                                let (name, field_value) = pair
+                                    .split_once('=')
+                                    .ok_or(::bitos::ParseFieldsError::Malformed)?;
+
+                                let id = #field_id_ident::from_name(name.trim())
+                                    .ok_or(::bitos::ParseFieldsError::UnknownField)?;
+                                let field_value = ::bitos::parse_field_value(field_value.trim())
+                                    .ok_or(::bitos::ParseFieldsError::InvalidValue)?;
+
+                                if field_value & !id.value_mask() != 0 {
+                                    return Err(::bitos::ParseFieldsError::ValueDoesNotFit);
+                                }
+
+                                value.set_by_id(id, field_value);
+                            }
+
+                            Ok(value)
+                        }
+                    }
+                }
+            });
+
+            let display = bitstruct.bitos_attr.display.then(|| {
+                quote::quote! {
+                    #[allow(clippy::all)]
+                    impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                        /// Formats this value as a comma separated list of `field=value` pairs, which
+                        /// can be parsed back if the struct also has the `parse` option.
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            for (i, id) in #field_id_ident::ALL.iter().enumerate() {
+                                if i != 0 {
+                                    f.write_str(",")?;
+                                }
+
+                                write!(f, "{}={}", id.name(), self.get_by_id(*id))?;
+                            }
+
+                            Ok(())
+                        }
+                    }
+                }
+            });
+
+            quote::quote! {
+                #[doc = #field_id_doc]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                #vis enum #field_id_ident {
+                    #(
+                        #[doc = #variant_docs]
+                        #variant_idents,
+                    )*
+                }
+
+                #[allow(dead_code, clippy::all)]
+                impl #field_id_ident {
+                    /// All field identifiers, in declaration order.
+                    pub const ALL: &'static [Self] = &[#(Self::#variant_idents),*];
+
+                    /// Returns the name of the identified field.
+                    #[inline(always)]
+                    pub const fn name(self) -> &'static str {
+                        match self {
+                            #(Self::#variant_idents => #names,)*
+                        }
+                    }
+
+                    /// Returns the identifier of the field with the given name, if any.
+                    #[inline(always)]
+                    pub fn from_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            #(#names => Some(Self::#variant_idents),)*
+                            _ => None,
+                        }
+                    }
+
+                    /// Returns the index of the lowest bit of the identified field.
+                    #[inline(always)]
+                    pub const fn start(self) -> u32 {
+                        match self {
+                            #(Self::#variant_idents => #starts,)*
+                        }
+                    }
+
+                    /// Returns a mask with the lowest `N` bits set, where `N` is the bit length of the
+                    /// identified field.
+                    #[inline(always)]
+                    pub const fn value_mask(self) -> u64 {
+                        match self {
+                            #(Self::#variant_idents => #value_masks,)*
+                        }
+                    }
+                }
+
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Returns the raw bits of the field identified by `id`.
+                    #[inline(always)]
+                    pub fn get_by_id(&self, id: #field_id_ident) -> u64 {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        (raw >> id.start()) & id.value_mask()
+                    }
+
+                    /// Sets the raw bits of the field identified by `id`. The value is masked to
+                    /// fit within the field.
+                    #[inline(always)]
+                    pub fn set_by_id(&mut self, id: #field_id_ident, value: u64) -> &mut Self {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        let (start, mask) = (id.start(), id.value_mask());
+
+                        let raw = (raw & !(mask << start)) | ((value & mask) << start);
+                        self.0 = <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw);
+                        self
+                    }
+
+                    /// Returns the raw bits of the field with the given name. Fields of `nested`
+                    /// bitstruct fields, which must have the `field_id` option too, can be accessed
+                    /// through dotted paths, e.g. `outer.inner`.
+                    #[inline]
+                    #[allow(unused_variables)]
+                    pub fn get_raw(&self, path: &str) -> ::core::option::Option<u64> {
+                        match path.split_once('.') {
+                            None => Some(self.get_by_id(#field_id_ident::from_name(path)?)),
+                            Some((name, rest)) => match name {
+                                #(#nested_names => self.#nested_getters().get_raw(rest),)*
+                                _ => None,
+                            },
+                        }
+                    }
+
+                    /// Sets the raw bits of the field with the given name, masked to fit within
+                    /// the field. Fields of `nested` bitstruct fields can be accessed through
+                    /// dotted paths, e.g. `outer.inner`. Returns [`None`] if there is no such
+                    /// field.
+                    #[inline]
+                    #[allow(unused_variables)]
+                    pub fn set_raw(&mut self, path: &str, value: u64) -> ::core::option::Option<&mut Self> {
+                        match path.split_once('.') {
+                            None => Some(self.set_by_id(#field_id_ident::from_name(path)?, value)),
+                            Some((name, rest)) => match name {
+                                #(
+                                    #nested_names => {
+                                        let mut nested = self.#nested_getters();
+                                        nested.set_raw(rest, value)?;
+                                        let _ = self.#nested_setters(nested);
+                                        Some(self)
+                                    }
+                                )*
+                                _ => None,
+                            },
+                        }
+                    }
+                }
+
+                #from_str
+
+                #display
+            }
+        });
+
+        let byte_aligned = matches!(bitstruct.bitos_attr.bitlen, 8 | 16 | 32 | 64);
+        let bytes = byte_aligned.then(|| {
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Returns the raw bytes of this value, in native endianness.
+                    #[inline(always)]
+                    pub fn raw_bytes(&self) -> &[u8] {
+                        // SAFETY: this type is `repr(transparent)` over a primitive integer, which
+                        // has no padding bytes and is always valid to read as bytes
+                        unsafe {
+                            ::core::slice::from_raw_parts(
+                                (self as *const Self).cast::<u8>(),
+                                ::core::mem::size_of::<Self>(),
+                            )
+                        }
+                    }
+                }
+            }
+        });
+
+        let bit_str = {
+            let bitlen = bitstruct.bitos_attr.bitlen;
+            let to_bit_str = cfg!(feature = "std").then(|| {
+                quote::quote! {
+                    /// Returns the raw bits of this value as a string of `0`s and `1`s, where the
+                    /// leftmost character is the most significant bit.
+                    pub fn to_bit_str(&self) -> ::std::string::String {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        (0..#bitlen)
+                            .rev()
+                            .map(|i| if (raw >> i) & 1 == 1 { '1' } else { '0' })
+                            .collect()
+                    }
+                }
+            });
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Creates a value of this type from a string of `0`s and `1`s, where the
+                    /// leftmost character is the most significant bit. Returns [`None`] if the
+                    /// string has the wrong length or contains any other character.
+                    pub fn from_bit_str(s: &str) -> ::core::option::Option<Self> {
+                        if s.len() != #bitlen {
+                            return None;
+                        }
+
+                        let mut raw = 0u64;
+                        for c in s.bytes() {
+                            let bit = match c {
+                                b'0' => 0,
+                                b'1' => 1,
+                                _ => return None,
+                            };
+
+                            raw = (raw << 1) | bit;
+                        }
+
+                        Some(Self(
+                            <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw),
+                            #phantom_data,
+                        ))
+                    }
+
+                    #to_bit_str
+                }
+            }
+        };
+
+        let plain = bitstruct.bitos_attr.plain.then(|| {
+            let data_ident = format_ident!("{}Data", ident);
+            let doc = format!("Plain representation of [`{ident}`], with one field per field of it.");
+            let plain_fields = fields.iter().filter(|f| !f.bits.reserved).collect::<Vec<_>>();
+
+            let data_fields = plain_fields.iter().map(|f| {
+                let StructField { vis, ident, ty, docs, .. } = f;
+                match ty {
+                    FieldTy::Try(ty) => quote::quote! { #(#docs)* #vis #ident: ::core::option::Option<#ty> },
+                    ty => quote::quote! { #(#docs)* #vis #ident: #ty },
+                }
+            });
+
+            let field_idents = plain_fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+            let getter_idents = plain_fields.iter().map(|f| f.accessor_ident("", ""));
+            let checked = bitstruct.bitos_attr.checked_setters;
+            let try_ = checked.then(|| quote::quote! { ? });
+            let set_fields = plain_fields.iter().map(|f| {
+                let ident = &f.ident;
+                let setter_ident = f.accessor_ident("set_", "");
+                match f.ty {
+                    FieldTy::Try(_) if !f.bits.with_presence && f.bits.invalid.is_none() => quote::quote! {
+                        if let Some(field) = data.#ident {
+                            value.#setter_ident(field)#try_;
+                        }
+                    },
+                    _ => quote::quote! {
+                        value.#setter_ident(data.#ident)#try_;
+                    },
+                }
+            });
+
+            // in checked mode, fields of `data` outside of their declared range are rejected
+            let from_data = if checked {
+                quote::quote! {
+                    #[allow(clippy::all)]
+                    impl #impl_generics ::core::convert::TryFrom<#data_ident #ty_generics> for #ident #ty_generics #where_clause {
+                        type Error = ::bitos::BitosError;
+
+                        /// [`None`] values of fallible fields leave their reset value in place.
+                        #[inline(always)]
+                        fn try_from(data: #data_ident #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                            let mut value = Self::new_reset();
+                            #(#set_fields)*
+                            Ok(value)
+                        }
+                    }
+                }
+            } else {
+                quote::quote! {
+                    #[allow(clippy::all)]
+                    impl #impl_generics ::core::convert::From<#data_ident #ty_generics> for #ident #ty_generics #where_clause {
+                        /// Fields of `data` which do not fit within their bit range are masked, and
+                        /// [`None`] values of fallible fields leave their reset value in place.
+                        #[inline(always)]
+                        fn from(data: #data_ident #ty_generics) -> Self {
+                            let mut value = Self::new_reset();
+                            #(#set_fields)*
+                            value
+                        }
+                    }
+                }
+            };
+
+            quote::quote! {
+                #[doc = #doc]
+                #(#user_derives)*
+                #[allow(clippy::all)]
+                #vis struct #data_ident #generics {
+                    #(#data_fields,)*
+                }
+
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::convert::From<#ident #ty_generics> for #data_ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn from(value: #ident #ty_generics) -> Self {
+                        Self {
+                            #(#field_idents: value.#getter_idents(),)*
+                        }
+                    }
+                }
+
+                #from_data
+            }
+        });
+
+        let aligned = bitstruct.bitos_attr.align.map(|align| {
+            let aligned_ident = format_ident!("{}Aligned", ident);
+            let align = proc_macro2::Literal::usize_unsuffixed(align);
+            let doc = format!("A [`{ident}`] aligned to {align} bytes.");
+
+            quote::quote! {
+                #[doc = #doc]
+                #[repr(align(#align))]
+                #[allow(clippy::all)]
+                #vis struct #aligned_ident #generics (pub #ident #ty_generics);
+
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::ops::Deref for #aligned_ident #ty_generics #where_clause {
+                    type Target = #ident #ty_generics;
+
+                    #[inline(always)]
+                    fn deref(&self) -> &Self::Target {
+                        &self.0
+                    }
+                }
+
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::ops::DerefMut for #aligned_ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        &mut self.0
+                    }
+                }
+
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::convert::From<#ident #ty_generics> for #aligned_ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn from(value: #ident #ty_generics) -> Self {
+                        Self(value)
+                    }
+                }
+            }
+        });
+
+        let transmutes = bitstruct.bitos_attr.transmute_from.iter().map(|other| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::convert::From<#other> for #ident #ty_generics #where_clause {
+                    /// Reinterprets the bits of a value with the same layout.
+                    #[inline(always)]
+                    fn from(value: #other) -> Self {
+                        Self::from_bits(::bitos::TryBits::to_bits(&value))
+                    }
+                }
+            }
+        });
+
+        let introspection = {
+            let accessible = fields
+                .iter()
+                .filter(|f| !f.bits.reserved)
+                .collect::<Vec<_>>();
+            let len = accessible.len();
+            let names = accessible
+                .iter()
+                .map(|f| f.ident.to_string())
+                .collect::<Vec<_>>();
+            let mask_idents = accessible
+                .iter()
+                .map(|f| format_ident!("{}_MASK", f.ident.to_string().to_shouty_snake_case()))
+                .collect::<Vec<_>>();
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Returns the name of every non-reserved field along with its parity, i.e.
+                    /// whether an odd number of its bits are set.
+                    #[inline]
+                    pub fn field_parities(
+                        &self,
+                    ) -> impl ::core::iter::Iterator<Item = (&'static str, bool)> {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        let parities: [(&'static str, bool); #len] = [
+                            #((#names, (raw & Self::#mask_idents).count_ones() % 2 == 1),)*
+                        ];
+
+                        parities.into_iter()
+                    }
+
+                    /// Returns the name of every non-reserved field along with its raw value,
+                    /// widened to a `u64`.
+                    #[inline]
+                    pub fn fields(&self) -> impl ::core::iter::Iterator<Item = (&'static str, u64)> {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        let fields: [(&'static str, u64); #len] = [
+                            #((#names, (raw & Self::#mask_idents) >> Self::#mask_idents.trailing_zeros()),)*
+                        ];
+
+                        fields.into_iter()
+                    }
+
+                    /// Returns the name of every non-reserved field whose value differs between
+                    /// `self` and `other`, along with both raw values as `(name, old, new)`.
+                    #[inline]
+                    pub fn diff(
+                        &self,
+                        other: &Self,
+                    ) -> impl ::core::iter::Iterator<Item = (&'static str, u64, u64)> {
+                        self.fields()
+                            .zip(other.fields())
+                            .filter_map(|((name, old), (_, new))| (old != new).then_some((name, old, new)))
+                    }
+
+                    /// Returns the name of every non-reserved field along with its mask.
+                    #[inline]
+                    pub fn field_masks() -> impl ::core::iter::Iterator<Item = (&'static str, #inner_ty)> {
+                        let masks: [(&'static str, u64); #len] = [
+                            #((#names, Self::#mask_idents),)*
+                        ];
+
+                        masks.into_iter().map(|(name, mask)| {
+                            (name, <#inner_ty as ::bitos::integer::UnsignedInt>::new(mask))
+                        })
+                    }
+                }
+            }
+        };
+
+        let halves = (bitstruct.bitos_attr.bitlen % 16 == 0).then(|| {
+            let half_bits = bitstruct.bitos_attr.bitlen / 2;
+            let half_ty = format_ident!("u{}", half_bits);
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Splits this value into its low and high halves, in that order.
+                    #[inline(always)]
+                    pub fn split(&self) -> (::bitos::integer::#half_ty, ::bitos::integer::#half_ty) {
+                        use ::bitos::integer::UnsignedInt;
+
+                        let raw = <#inner_ty as UnsignedInt>::value(self.0);
+                        (
+                            <::bitos::integer::#half_ty as UnsignedInt>::new(raw),
+                            <::bitos::integer::#half_ty as UnsignedInt>::new(raw >> #half_bits),
+                        )
+                    }
+
+                    /// Creates a value of this type from its low and high halves. This is the
+                    /// inverse of [`Self::split`].
+                    #[inline(always)]
+                    pub fn join(
+                        low: ::bitos::integer::#half_ty,
+                        high: ::bitos::integer::#half_ty,
+                    ) -> Self {
+                        use ::bitos::integer::UnsignedInt;
+
+                        let raw = <::bitos::integer::#half_ty as UnsignedInt>::value(low)
+                            | (<::bitos::integer::#half_ty as UnsignedInt>::value(high) << #half_bits);
+                        Self(<#inner_ty as UnsignedInt>::new(raw), #phantom_data)
+                    }
+                }
+            }
+        });
+
+        // the primitive storage of the inner type
+        let storage_ty = format_ident!(
+            "u{}",
+            bitstruct.bitos_attr.bitlen.next_power_of_two().max(8)
+        );
+
+        // volatile accesses target the primitive storage, so that bits outside of the struct's
+        // width are masked off when reading
+        let volatile = quote::quote! {
+            #[allow(dead_code, clippy::all)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Reads a value of this type from `ptr` using a volatile read.
+                ///
+                /// # Safety
+                /// `ptr` must satisfy the requirements of [`core::ptr::read_volatile`].
+                #[inline(always)]
+                pub unsafe fn read_volatile(ptr: *const #storage_ty) -> Self {
+                    let raw = unsafe { ::core::ptr::read_volatile(ptr) };
+                    Self(
+                        <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw as u64),
+                        #phantom_data,
+                    )
+                }
+
+                /// Writes this value to `ptr` using a volatile write.
+                ///
+                /// # Safety
+                /// `ptr` must satisfy the requirements of [`core::ptr::write_volatile`].
+                #[inline(always)]
+                pub unsafe fn write_volatile(&self, ptr: *mut #storage_ty) {
+                    let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                    unsafe { ::core::ptr::write_volatile(ptr, raw as #storage_ty) };
+                }
+            }
+        };
+
+        let le_bytes = (bitstruct.bitos_attr.bitlen % 8 == 0).then(|| {
+            let bitlen = bitstruct.bitos_attr.bitlen;
+            let byte_len = quote::quote! { { ::bitos::byte_len(#bitlen) } };
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Returns the raw bits of this value as a little-endian byte array.
+                    #[inline(always)]
+                    pub fn to_le_bytes(&self) -> [u8; #byte_len] {
+                        let raw = <#inner_ty as ::bitos::integer::UnsignedInt>::value(self.0);
+                        let bytes = (raw as #storage_ty).to_le_bytes();
+
+                        let mut result = [0; #byte_len];
+                        result.copy_from_slice(&bytes[..#byte_len]);
+                        result
+                    }
+
+                    /// Creates a value of this type from its raw bits as a little-endian byte
+                    /// array.
+                    #[inline(always)]
+                    pub fn from_le_bytes(bytes: [u8; #byte_len]) -> Self {
+                        let mut raw = [0; ::core::mem::size_of::<#storage_ty>()];
+                        raw[..#byte_len].copy_from_slice(&bytes);
+
+                        let raw = #storage_ty::from_le_bytes(raw);
+                        Self(
+                            <#inner_ty as ::bitos::integer::UnsignedInt>::new(raw as u64),
+                            #phantom_data,
+                        )
+                    }
+                }
+            }
+        });
+
+        // raw bits are always kept in native order, big-endian wire values are only converted
+        // through these explicitly
+        let be = bitstruct.bitos_attr.from_be.then(|| {
+            let bitlen = bitstruct.bitos_attr.bitlen;
+            let byte_len = quote::quote! { { ::bitos::byte_len(#bitlen) } };
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Creates a value of this type from its raw bits in big-endian byte order.
+                    #[inline(always)]
+                    pub const fn from_be_bits(value: #inner_ty) -> Self {
+                        Self(<#inner_ty>::from_be(value), #phantom_data)
+                    }
+
+                    /// Returns the raw bits of this value in big-endian byte order.
+                    #[inline(always)]
+                    pub const fn to_be_bits(&self) -> #inner_ty {
+                        self.0.to_be()
+                    }
+
+                    /// Creates a value of this type from its raw bits as a big-endian byte array.
+                    #[inline(always)]
+                    pub const fn from_be_bytes(bytes: [u8; #byte_len]) -> Self {
+                        Self(<#inner_ty>::from_be_bytes(bytes), #phantom_data)
+                    }
+
+                    /// Returns the raw bits of this value as a big-endian byte array.
+                    #[inline(always)]
+                    pub const fn to_be_bytes(&self) -> [u8; #byte_len] {
+                        self.0.to_be_bytes()
+                    }
+                }
+            }
+        });
+
+        let atomic = (cfg!(feature = "atomic") && byte_aligned).then(|| {
+            let atomic_ty = format_ident!("AtomicU{}", bitstruct.bitos_attr.bitlen);
+
+            quote::quote! {
+                #[allow(dead_code, clippy::all)]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Loads a value of this type from the given atomic.
+                    #[inline(always)]
+                    pub fn load(
+                        atomic: &::core::sync::atomic::#atomic_ty,
+                        order: ::core::sync::atomic::Ordering,
+                    ) -> Self {
+                        Self::from_bits(atomic.load(order))
+                    }
+
+                    /// Stores this value into the given atomic.
+                    #[inline(always)]
+                    pub fn store(
+                        &self,
+                        atomic: &::core::sync::atomic::#atomic_ty,
+                        order: ::core::sync::atomic::Ordering,
+                    ) {
+                        atomic.store(self.to_bits(), order);
+                    }
+
+                    /// Atomically modifies the value stored in the given atomic by applying `f` to
+                    /// it, retrying if it was concurrently changed. Returns the previous value.
+                    #[inline(always)]
+                    pub fn modify(
+                        atomic: &::core::sync::atomic::#atomic_ty,
+                        set_order: ::core::sync::atomic::Ordering,
+                        fetch_order: ::core::sync::atomic::Ordering,
+                        mut f: impl FnMut(Self) -> Self,
+                    ) -> Self {
+                        let result = atomic.fetch_update(set_order, fetch_order, |value| {
+                            Some(f(Self::from_bits(value)).to_bits())
+                        });
+
+                        match result {
+                            Ok(value) | Err(value) => Self::from_bits(value),
+                        }
+                    }
+                }
+            }
+        });
+
+        // standard traits are implemented on the raw bits directly so that no bounds are required
+        // on type parameters, which only live in phantom data
+        let clone = generate_clone.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn clone(&self) -> Self {
+                        Self(self.0, #phantom_data)
+                    }
+                }
+            }
+        });
+
+        let copy = generate_copy.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::marker::Copy for #ident #ty_generics #where_clause {}
+            }
+        });
+
+        let hash = generate_hash.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::hash::Hash for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        ::core::hash::Hash::hash(&self.0, state);
+                    }
+                }
+            }
+        });
+
+        let partial_ord = generate_partial_ord.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::cmp::PartialOrd for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn partial_cmp(
+                        &self,
+                        other: &Self,
+                    ) -> ::core::option::Option<::core::cmp::Ordering> {
+                        ::core::cmp::PartialOrd::partial_cmp(&self.0, &other.0)
+                    }
+                }
+            }
+        });
+
+        let ord = generate_ord.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::cmp::Ord for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        ::core::cmp::Ord::cmp(&self.0, &other.0)
+                    }
+                }
+            }
+        });
+
+        let partial_eq = generate_partial_eq.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::cmp::PartialEq for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn eq(&self, other: &Self) -> bool {
+                        self.0 == other.0
+                    }
+                }
+            }
+        });
+
+        let eq = generate_eq.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::cmp::Eq for #ident #ty_generics #where_clause {}
+            }
+        });
+
+        let default = generate_default.then(|| {
+            quote::quote! {
+                #[allow(clippy::all)]
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn default() -> Self {
+                        Self::new_reset()
+                    }
+                }
+            }
+        });
+
+        let bits = bitstruct.bitos_attr.bitlen;
+        let bitlen = bits as u8;
         let extra_impls = quote::quote! {
             #dbg
+            #clone
+            #copy
+            #hash
+            #partial_eq
+            #eq
+            #partial_ord
+            #ord
+            #default
+            #field_id
+            #bytes
+            #plain
+            #aligned
+            #(#transmutes)*
+            #introspection
+            #halves
+            #volatile
+            #le_bytes
+            #be
+            #bit_str
+            #atomic
 
             #[allow(clippy::all)]
             impl #impl_generics ::bitos::TryBits for #ident #ty_generics #where_clause {
@@ -622,6 +2781,28 @@ impl BitStruct {
                 }
             }
 
+            #[allow(clippy::all)]
+            impl #impl_generics ::core::convert::TryFrom<u64> for #ident #ty_generics #where_clause {
+                type Error = ::bitos::BitosError;
+
+                #[inline(always)]
+                fn try_from(value: u64) -> ::core::result::Result<Self, Self::Error> {
+                    if value & !::bitos::integer::mask_to_width(u64::MAX, #bitlen) != 0 {
+                        return Err(::bitos::BitosError::ValueDoesNotFit { width: #bitlen });
+                    }
+
+                    Ok(Self::from_bits(<#inner_ty as ::bitos::integer::UnsignedInt>::new(value)))
+                }
+            }
+
+            #[allow(clippy::all)]
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for u64 #where_clause {
+                #[inline(always)]
+                fn from(value: #ident #ty_generics) -> u64 {
+                    <#inner_ty as ::bitos::integer::UnsignedInt>::value(value.to_bits())
+                }
+            }
+
             #[allow(clippy::all)]
             impl #impl_generics ::bitos::Bits for #ident #ty_generics #where_clause {
                 #[inline(always)]
@@ -629,6 +2810,12 @@ impl BitStruct {
                     Self(value, #phantom_data)
                 }
             }
+
+            #[allow(clippy::all)]
+            impl #impl_generics ::bitos::BitosStruct for #ident #ty_generics #where_clause {
+                const BITS: usize = #bits;
+                type Storage = #inner_ty;
+            }
         };
 
         Ok(BitStruct {